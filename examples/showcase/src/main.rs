@@ -0,0 +1,57 @@
+use leptos::logging::log;
+use leptos::prelude::*;
+use leptos_color::{
+    components::{color_picker::ColorPicker, swatches::Swatches},
+    theme::Theme,
+    Color,
+};
+
+/// The preset palette shown below the picker.
+const PRESETS: [&str; 8] = [
+    "#ef4444",
+    "#f97316",
+    "#eab308",
+    "#22c55e",
+    "#06b6d4",
+    "#3b82f6",
+    "#8b5cf6",
+    "#ec4899cc",
+];
+
+fn main() {
+    console_error_panic_hook::set_once();
+    leptos::mount::mount_to_body(App);
+}
+
+#[component]
+fn App() -> impl IntoView {
+    let color = RwSignal::new(Color::from_rgba8(59, 130, 246, 200));
+    let presets = PRESETS
+        .iter()
+        .map(|hex| hex.parse::<Color>().unwrap())
+        .collect::<Vec<_>>();
+
+    let on_change = Callback::new(move |c: Color| {
+        log!("color changed: {}", c.to_hex_string());
+        color.set(c);
+    });
+
+    view! {
+        <h1>"Leptos Color showcase"</h1>
+        <p>"Click the preview swatch to copy the color."</p>
+        <ColorPicker
+            theme=Theme::dark()
+            color=color
+            hide_alpha=false
+            show_alpha_label=true
+            preview_clickable=true
+            on_change=on_change
+        />
+        <Swatches
+            colors=presets
+            selected=Signal::derive(move || Some(color.get()))
+            on_select=on_change
+        />
+        <p>"Current color: " {move || color.get().to_hex_string()}</p>
+    }
+}
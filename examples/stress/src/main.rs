@@ -0,0 +1,94 @@
+use leptos::prelude::*;
+use leptos_color::{components::color_picker::ColorPicker, Color};
+use web_sys::Performance;
+
+/// Number of independent pickers rendered on the page.
+const PICKERS: usize = 100;
+
+fn main() {
+    console_error_panic_hook::set_once();
+    leptos::mount::mount_to_body(App);
+}
+
+/// Samples the time between animation frames and reports the mean and the worst frame of each
+/// second, in milliseconds.
+fn sample_frames(
+    performance: Performance,
+    last: f64,
+    second_start: f64,
+    mut frames: Vec<f64>,
+    report: WriteSignal<Option<(f64, f64)>>,
+) {
+    request_animation_frame(move || {
+        let now = performance.now();
+        frames.push(now - last);
+        if now - second_start < 1000.0 {
+            return sample_frames(performance, now, second_start, frames, report);
+        }
+        let mean = frames.iter().sum::<f64>() / frames.len() as f64;
+        let worst = frames.iter().copied().fold(0.0, f64::max);
+        report.set(Some((mean, worst)));
+        frames.clear();
+        sample_frames(performance, now, now, frames, report);
+    });
+}
+
+#[component]
+fn App() -> impl IntoView {
+    // Every picker gets its own signal so updates in one must not re-run the others
+    let colors = (0..PICKERS)
+        .map(|i| {
+            let hue = i as f32 * 360.0 / PICKERS as f32;
+            RwSignal::new(Color::from_hsva(hue, 1.0, 1.0, 1.0))
+        })
+        .collect::<Vec<_>>();
+
+    let (frame_times, set_frame_times) = signal(None);
+    let performance = window()
+        .performance()
+        .expect("the Performance API is unavailable");
+    let now = performance.now();
+    sample_frames(performance, now, now, Vec::new(), set_frame_times);
+
+    // Counts the colors emitted by all pickers, and the runs of one effect per picker that
+    // depends on the same signals as that picker's CSS variable effect. `on_change` writes two
+    // of its signals, so more runs than changes would mean the writes are not coalesced.
+    let changes = RwSignal::new(0_u32);
+    let effect_runs = RwSignal::new(0_u32);
+
+    view! {
+        <h1>{format!("{PICKERS} color pickers")}</h1>
+        <p>
+            {move || match frame_times.get() {
+                Some((mean, worst)) => {
+                    format!("Frame time: {mean:.1} ms mean, {worst:.1} ms worst")
+                }
+                None => "Frame time: measuring…".to_string(),
+            }}
+        </p>
+        <p>
+            {move || format!("{} changes, {} effect runs", changes.get(), effect_runs.get())}
+        </p>
+        <div style="display: flex; flex-wrap: wrap; gap: 8px;">
+            {colors
+                .into_iter()
+                .map(|color| {
+                    let picker_changes = RwSignal::new(0_u32);
+                    Effect::new(move |previous: Option<()>| {
+                        color.track();
+                        picker_changes.track();
+                        if previous.is_some() {
+                            effect_runs.update(|runs| *runs += 1);
+                        }
+                    });
+                    let on_change = move |c| {
+                        changes.update(|changes| *changes += 1);
+                        picker_changes.update(|changes| *changes += 1);
+                        color.set(c);
+                    };
+                    view! { <ColorPicker color=color on_change=on_change /> }
+                })
+                .collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,76 @@
+use csscolorparser::Color;
+
+/// Converts a channel value in `0..=255`, as typed into an input, to the `0..=1` range of [`Color`].
+///
+/// Values outside of the range are clamped. Integers round-trip exactly through
+/// [`channel_to_u8`] and `Color::to_rgba8`, so typing `127` always reads back as `127`.
+pub fn channel_from_255(value: f32) -> f32 {
+    value.clamp(0.0, 255.0) / 255.0
+}
+
+/// Converts a `0..=1` channel of [`Color`] to the nearest 8-bit value.
+pub fn channel_to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// CSS string formatting for [`Color`].
+///
+/// `Color` is a foreign type, so these live in an extension trait. It is implemented for
+/// `Color` and re-exported from the [prelude](crate::prelude).
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::prelude::*;
+///
+/// let color = rgba8(255, 128, 0, 51);
+/// assert_eq!(color.to_rgba_css(), "rgba(255, 128, 0, 0.2)");
+/// assert_eq!(color.to_hex6(), "#ff8000");
+/// assert_eq!(color.to_hex8(), "#ff800033");
+/// ```
+pub trait ColorExt {
+    /// `rgba(r, g, b, a)` with 8-bit channels and a `0..=1` alpha in 8-bit steps.
+    fn to_rgba_css(&self) -> String;
+    /// `hsla(h, s%, l%, a)` with values rounded to one decimal.
+    fn to_hsla_css(&self) -> String;
+    /// `#rrggbb`, ignoring alpha.
+    fn to_hex6(&self) -> String;
+    /// `#rrggbbaa`, including alpha even when the color is opaque.
+    fn to_hex8(&self) -> String;
+    /// `[r * a, g * a, b * a, a]`, the premultiplied-alpha form used by WebGL and canvas pipelines.
+    fn to_premultiplied(&self) -> [f32; 4];
+}
+
+impl ColorExt for Color {
+    fn to_rgba_css(&self) -> String {
+        let [r, g, b, a] = self.to_rgba8();
+        format!("rgba({r}, {g}, {b}, {})", channel_from_255(a as f32))
+    }
+
+    fn to_hsla_css(&self) -> String {
+        let [h, s, l, a] = self.to_hsla();
+        let round1 = |value: f32| (value * 10.0).round() / 10.0;
+        let h = if h.is_nan() { 0.0 } else { h };
+        format!(
+            "hsla({}, {}%, {}%, {})",
+            round1(h),
+            round1(s * 100.0),
+            round1(l * 100.0),
+            (a * 1000.0).round() / 1000.0
+        )
+    }
+
+    fn to_hex6(&self) -> String {
+        let [r, g, b, _] = self.to_rgba8();
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+
+    fn to_hex8(&self) -> String {
+        let [r, g, b, a] = self.to_rgba8();
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+
+    fn to_premultiplied(&self) -> [f32; 4] {
+        [self.r * self.a, self.g * self.a, self.b * self.a, self.a]
+    }
+}
@@ -3,7 +3,7 @@ use leptos::logging::warn;
 use leptos::prelude::*;
 
 use crate::{
-    hooks::use_position::{use_position, UsePositionProps},
+    components::{orientation::Orientation, slider::Slider},
     mount_style::mount_style,
 };
 /// A component for selecting the alpha (transparency) value of a color.
@@ -17,12 +17,25 @@ use crate::{
 ///   The callback receives a tuple of (left, top) values, where:
 ///   - `left` represents the alpha value (0 = fully transparent, 1 = fully opaque)
 ///   - `top` is not used for this component but is included for consistency with other components
+/// * `orientation`: An optional `Signal<Orientation>`. In `Orientation::Vertical` the slider runs
+///   from transparent at the bottom to opaque at the top, and the alpha is taken from the vertical
+///   pointer position. Defaults to `Orientation::Horizontal`.
+/// * `locked`: An optional `Signal<bool>` that keeps the control visible but ignores pointer input
+///   and dims it via the `leptos-color-locked` class.
+/// * `value`: An optional `Signal<f64>` with the current alpha in `0..=1`. It places the handle, is
+///   exposed to assistive technology and used as the starting point for keyboard steps. Without it,
+///   the handle follows the `--lpc-alpha-pointer` variable and the slider is not focusable.
+/// * `checkerboard`: An optional `Signal<bool>`, `true` by default. When `false`, the checkerboard
+///   layer is not rendered and the gradient fades into the theme's input background instead.
 ///
 /// # Behavior
 ///
 /// - The component renders a horizontal bar with a checkered background to represent transparency.
 /// - Users can click, tap, or drag along this bar to select an alpha value.
-/// - The component uses the `use_position` hook to handle mouse and touch interactions.
+/// - Built on [`Slider`]: the slider is focusable with `role="slider"` and reports `value` as a percentage. Arrow keys step
+///   the alpha by 1% (10% with Shift), Home and End select 0% and 100%.
+/// - The component is built on the shared slider primitive, which uses the `use_position` hook to
+///   handle mouse and touch interactions.
 /// - As the user interacts with the component, the `on_change` callback is triggered with
 ///   the new position values.
 ///
@@ -54,23 +67,31 @@ use crate::{
 ///
 /// This example creates an `Alpha` component and displays the selected alpha value.
 #[component]
-pub fn Alpha(#[prop(into)] on_change: Callback<(f64, f64)>) -> impl IntoView {
+pub fn Alpha(
+    #[prop(into)] on_change: Callback<(f64, f64)>,
+    #[prop(into, optional)] locked: Signal<bool>,
+    #[prop(into, optional)] orientation: Signal<Orientation>,
+    #[prop(into, default = true.into())] checkerboard: Signal<bool>,
+    #[prop(optional)] value: Option<Signal<f64>>,
+) -> impl IntoView {
     mount_style("Alpha", include_str!("./alpha.css"));
-    let handle_move = Callback::new(move |(left, top): (f64, f64)| on_change.run((left, top)));
-
-    // Use the `use_position` hook to get the ref and handle_start function
-    let (ref_div, handle_start) = use_position(UsePositionProps {
-        on_move: handle_move.clone(),
-    });
+    let value = Signal::derive(move || value.map(|alpha| alpha.get()));
     view! {
-        <div class="leptos-color-alpha-container" node_ref={ref_div} on:touchstart=move |ev| {
-            handle_start.run(ev.into())} on:mousedown=move |ev| {
-            handle_start.run(ev.into())}>
+        <Slider
+            class="leptos-color-alpha-container"
+            vertical_class="leptos-color-alpha-vertical"
+            pointer_class="leptos-color-alpha-pointer"
+            handle_class="leptos-color-alpha-slider"
+            orientation=orientation
+            locked=locked
+            value=value
+            label="Alpha"
+            on_change=on_change
+        >
             <div class="leptos-color-alpha-alpha" />
-            <div class="leptos-color-alpha-checkboard" />
-            <div class="leptos-color-alpha-pointer">
-                <div class="leptos-color-alpha-slider" />
-            </div>
-        </div>
+            <Show when=move || checkerboard.get()>
+                <div class="leptos-color-alpha-checkboard" />
+            </Show>
+        </Slider>
     }
 }
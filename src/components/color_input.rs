@@ -1,14 +1,38 @@
-use crate::{components::color_picker::ColorPicker, theme::Theme};
-use csscolorparser::Color;
-use floating_ui_leptos::{
-    use_floating, Alignment, AutoPlacement, AutoPlacementOptions, AutoUpdateOptions,
-    DetectOverflowOptions, Flip, FlipOptions, MiddlewareVec, Offset, OffsetOptions, Padding,
-    Placement, Shift, ShiftOptions, UseFloatingOptions, UseFloatingReturn,
+use crate::{
+    components::{color_picker::ColorPicker, popover::PickerPopover},
+    format::{format_color, parse_color, ColorFormat},
+    theme::{use_theme, Theme},
 };
-use leptos::html::{Div, Input};
-use leptos::{ev, prelude::*};
+use csscolorparser::Color;
+use leptos::prelude::*;
 use leptos_node_ref::AnyNodeRef;
 use web_sys::wasm_bindgen::JsCast as _;
+use web_sys::HtmlInputElement;
+
+/// Why the popover of a [`ColorInput`] opened or closed, see [`OpenChange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenReason {
+    /// The input was clicked.
+    TriggerClick,
+    /// A click landed outside of the input and the popover.
+    OutsideClick,
+    /// Escape was pressed in the input.
+    Escape,
+    /// Enter was pressed in the input, committing the typed color.
+    Select,
+    /// The `open` signal was changed by the app.
+    Programmatic,
+}
+
+/// An open or close of the popover of a [`ColorInput`], passed to `on_open_change`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpenChange {
+    /// Whether the popover is now open.
+    pub open: bool,
+    /// What opened or closed it.
+    pub reason: OpenReason,
+}
+
 /// A color input component with a clickable color picker popover.
 ///
 /// This component provides an input field for color values and a floating color picker
@@ -17,22 +41,50 @@ use web_sys::wasm_bindgen::JsCast as _;
 ///
 /// # Props
 ///
-/// * `theme`: A `MaybeSignal<Theme>` representing the theme for the component. Defaults to `Theme::default()`.
+/// * `theme`: An optional `Signal<Theme>` representing the theme for the component. Falls back to the
+///   theme of the nearest `ThemeProvider`, then to `Theme::default()`.
 /// * `color`: A `Signal<Color>` representing the current color value.
 /// * `hide_alpha`: An optional `MaybeSignal<bool>` to hide the alpha channel in the color picker.
 /// * `hide_hex`: An optional `MaybeSignal<bool>` to hide the hexadecimal color input in the color picker.
 /// * `hide_rgb`: An optional `MaybeSignal<bool>` to hide the RGB color input in the color picker.
+/// * `force_opaque`: An optional `Signal<bool>` that forces the alpha of every emitted color to `1.0`.
+/// * `format`: An optional `Signal<ColorFormat>` for the value displayed in the input field. It is also
+///   passed to the embedded color picker so both agree. Defaults to `ColorFormat::Rgba`.
+/// * `toggle_on_trigger`: An optional `Signal<bool>`. When `true` (the default), clicking the input
+///   toggles the popover. When `false`, clicking only opens it, so re-focusing the field to edit it
+///   does not close the picker; it then closes on an outside click or Escape.
+/// * `name`: An optional name under which the color is submitted with a surrounding `<form>`. The value
+///   is carried by a hidden input in the configured `format` and updated on every committed change.
+/// * `required`: An optional `Signal<bool>` marking the input as required for form validation.
+/// * `open`: An optional `RwSignal<bool>` controlling whether the popover is open. Provide it to open
+///   or close the picker from outside, e.g. from a custom modal. Defaults to an internal signal.
+/// * `manage_outside_click`: An optional `Signal<bool>`, `true` by default. When `false`, no window
+///   click listener is attached and clicks outside do not close the popover, leaving closing to
+///   the `open` signal (and Escape or the trigger). Useful when the app detects outside clicks itself.
+/// * `on_open_change`: An optional `Callback<OpenChange>` called whenever the popover opens or closes,
+///   with the [`OpenReason`]. It lets apps tell a dismissal (outside click, Escape) from a selection,
+///   e.g. to decide whether to revert a previewed color.
 /// * `on_change`: A `Callback<Color>` that is called when the color value changes.
 /// * `class`: An optional `MaybeProp<String>` for additional CSS classes to apply to the input element.
 ///
 /// # Behavior
 ///
-/// - The input field displays the current color value in RGBA format.
-/// - Clicking the input field toggles the color picker popover.
-/// - The color picker closes when clicking outside or clicking the input again.
+/// - The input field displays the current color value in the configured `format`.
+/// - Typed values are accepted in any CSS color format, regardless of `format`.
+/// - Clicking the input field toggles the color picker popover (or only opens it, see `toggle_on_trigger`).
+/// - The color picker closes when clicking outside (unless `manage_outside_click` is `false`),
+///   pressing Escape or Enter in the input, or clicking the input again. Enter also commits the typed value.
 /// - The color picker floats relative to the input using the `floating_ui_leptos` crate.
+/// - The color picker is only mounted while the popover is open, so many closed inputs on a page
+///   cost no reactive work. It always opens showing the current `color`.
 /// - Changes to the color can be made either by editing the input field directly or using the color picker.
 /// - The `on_change` callback is triggered when a valid color value is entered or selected.
+/// - With server-side rendering, the input is rendered with a `value` attribute. When the app hydrates,
+///   a valid value in that attribute that differs from `color` (e.g. because the server rendered a
+///   submitted form value) is reconciled once through `on_change`, so the field does not flash back to
+///   the initial signal value. After that first run, the signal always wins.
+/// - While the typed text is not a valid color, the input reports a custom validity error, so native
+///   form validation blocks submission and `:invalid` styles apply.
 ///
 /// # Example
 ///
@@ -63,105 +115,150 @@ use web_sys::wasm_bindgen::JsCast as _;
 ///
 /// Additional styling can be applied through the `class` prop for the input element
 /// or by targeting the `.color-input-container` and `.color-picker-popover` classes.
+/// The popover's appearance comes from a stylesheet rather than inline styles, so a rule such as
+/// `.my-toolbar .color-picker-popover { z-index: 20; border-radius: 0; }` overrides it. Only
+/// its position and visibility are set inline. By default it sits at `z-index: 1000`.
 #[component]
 pub fn ColorInput(
-    #[prop(into, default=Theme::default().into())] theme: Signal<Theme>,
+    #[prop(into, optional)] theme: Option<Signal<Theme>>,
     #[prop(into)] color: Signal<Color>,
     #[prop(into, optional)] hide_alpha: Signal<bool>,
     #[prop(into, optional)] hide_hex: Signal<bool>,
     #[prop(into, optional)] hide_rgb: Signal<bool>,
+    #[prop(into, optional)] force_opaque: Signal<bool>,
+    #[prop(into, default = ColorFormat::Rgba.into())] format: Signal<ColorFormat>,
+    #[prop(into, default = true.into())] toggle_on_trigger: Signal<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] required: Signal<bool>,
+    #[prop(optional)] open: Option<RwSignal<bool>>,
+    #[prop(into, default = true.into())] manage_outside_click: Signal<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<OpenChange>>,
     #[prop(into)] on_change: Callback<Color>,
     #[prop(into, optional)] class: MaybeProp<String>,
 ) -> impl IntoView {
+    let theme = use_theme(theme);
+    // `ColorPicker` applies `force_opaque` itself, so only typed text needs the clamp.
+    let on_typed_change = Callback::new(move |mut color: Color| {
+        if force_opaque.get_untracked() {
+            color.a = 1.0;
+        }
+        on_change.run(color);
+    });
     let reference_ref = AnyNodeRef::new();
-    let floating_ref = AnyNodeRef::new();
-    let (open, set_open) = signal(false);
+    let (open, set_open) = open.unwrap_or_else(|| RwSignal::new(false)).split();
 
-    // Click outside detection
-    let click_outside = window_event_listener(ev::click, move |ev| {
-        if !open.get() {
-            return;
+    // Internal open/close sites record their reason before writing `open`; any other change
+    // came from the app through the `open` signal.
+    let pending_reason = StoredValue::new(None::<OpenReason>);
+    let set_open_for = move |value: bool, reason: OpenReason| {
+        if open.get_untracked() != value {
+            pending_reason.set_value(Some(reason));
+            set_open.set(value);
         }
-
-        let target = ev.target();
-        let target_node = target.and_then(|t| t.dyn_into::<web_sys::Node>().ok());
-
-        if let Some(target_node) = target_node {
-            if !reference_ref
-                .get()
-                .map(|r| r.contains(Some(&target_node)))
-                .unwrap_or(false)
-                && !floating_ref
-                    .get()
-                    .map(|f| f.contains(Some(&target_node)))
-                    .unwrap_or(false)
-            {
-                set_open.set(false);
+    };
+    Effect::new(move |previous: Option<bool>| {
+        let open = open.get();
+        let reason = pending_reason
+            .try_update_value(Option::take)
+            .flatten()
+            .unwrap_or(OpenReason::Programmatic);
+        if let (Some(previous), Some(on_open_change)) = (previous, on_open_change) {
+            if previous != open {
+                on_open_change.run(OpenChange { open, reason });
             }
         }
+        open
     });
 
-    let middleware: MiddlewareVec = vec![
-        Box::new(Offset::new(OffsetOptions::Value(8.0))), // Increased offset
-        Box::new(Flip::new(FlipOptions::default().cross_axis(false))),
-    ];
+    // A committed color replaces whatever invalid text was typed, so clear the validity error.
+    Effect::new(move |_| {
+        color.track();
+        if let Some(input) = reference_ref
+            .get_untracked()
+            .and_then(|element| element.dyn_into::<HtmlInputElement>().ok())
+        {
+            input.set_custom_validity("");
+        }
+    });
 
-    on_cleanup(move || {
-        click_outside.remove();
+    // Reconcile once with the server-rendered value; effects only run in the browser.
+    Effect::new(move |_| {
+        let Some(rendered) = reference_ref
+            .get_untracked()
+            .and_then(|element| element.get_attribute("value"))
+            .and_then(|value| parse_color(&value))
+        else {
+            return;
+        };
+        let format = format.get_untracked();
+        if color.with_untracked(|c| format_color(c, format)) != format_color(&rendered, format) {
+            on_typed_change.run(rendered);
+        }
     });
-    let UseFloatingReturn {
-        floating_styles, ..
-    } = use_floating(
-        reference_ref,
-        floating_ref,
-        UseFloatingOptions::default()
-            .open(open.into())
-            .placement(Placement::Bottom.into())
-            .middleware(send_wrapper::SendWrapper::new(middleware).into())
-            .while_elements_mounted_auto_update(),
-    );
-    let on_change2 = Callback::new(move |color: Color| on_change.run(color));
+
     view! {
         <div class="color-input-container" style="position: relative;">
             <input
                 class={move || class.get().unwrap_or("".to_string())}
                 node_ref=reference_ref
-                on:click=move |_| set_open.update(|open| *open = !*open)
-                prop:value=move || {
-                    let rgba = color.get().to_rgba8();
-                    format!("rgba({},{},{},{})", rgba[0], rgba[1], rgba[2], rgba[3])
+                on:click=move |_| {
+                    if toggle_on_trigger.get_untracked() {
+                        set_open_for(!open.get_untracked(), OpenReason::TriggerClick);
+                    } else {
+                        set_open_for(true, OpenReason::TriggerClick);
+                    }
+                }
+                on:keydown=move |ev| {
+                    match ev.key().as_str() {
+                        "Escape" => set_open_for(false, OpenReason::Escape),
+                        "Enter" => set_open_for(false, OpenReason::Select),
+                        _ => {}
+                    }
+                }
+                value=color.with_untracked(|c| format_color(c, format.get_untracked()))
+                prop:value=move || color.with(|c| format_color(c, format.get()))
+                required=move || required.get()
+                on:input=move |ev| {
+                    let input = event_target::<HtmlInputElement>(&ev);
+                    let value = input.value();
+                    if value.trim().is_empty() || parse_color(&value).is_some() {
+                        input.set_custom_validity("");
+                    } else {
+                        input.set_custom_validity("Enter a valid CSS color");
+                    }
                 }
                 on:change=move |ev| {
-                    if let Ok(new_color) = event_target_value(&ev).parse::<Color>() {
-                        on_change.run(new_color);
+                    if let Some(new_color) = parse_color(&event_target_value(&ev)) {
+                        on_typed_change.run(new_color);
                     }
                 }
             />
-            <div
-                node_ref=floating_ref
-                class="color-picker-popover"
-                style:display=move || if open.get() { "block" } else { "none" }
-                style:background-color="#fff"
-                style:box-shadow="0 2px 10px rgba(0, 0, 0, 0.1)"
-                style:border-radius="4px"
-                style:z-index="1000"
-                style:opacity=move || if open.get() { "1" } else { "0" }
-                style:transition="opacity 0.2s ease-in-out"
-                style:position=move || floating_styles.get().style_position()
-                style:top=move || floating_styles.get().style_top()
-                style:left=move || floating_styles.get().style_left()
-                style:transform=move || floating_styles.get().style_transform().unwrap_or_default()
-                style:will-change=move || floating_styles.get().style_will_change().unwrap_or_default()
+            <input
+                type="hidden"
+                name=move || name.get()
+                prop:value=move || color.with(|c| format_color(c, format.get()))
+            />
+            <PickerPopover
+                reference_ref=reference_ref
+                open=open
+                set_open=set_open
+                manage_outside_click=manage_outside_click
+                on_outside_click=move |_| pending_reason.set_value(Some(OpenReason::OutsideClick))
             >
-                <ColorPicker
-                    theme=theme
-                    color=color
-                    hide_hex=hide_hex
-                    hide_rgb=hide_rgb
-                    hide_alpha=hide_alpha
-                    on_change=on_change2
-                />
-            </div>
+                // Only mounted while open, so closed popovers run no picker effects.
+                <Show when=move || open.get()>
+                    <ColorPicker
+                        theme=theme
+                        color=color
+                        hide_hex=hide_hex
+                        hide_rgb=hide_rgb
+                        hide_alpha=hide_alpha
+                        force_opaque=force_opaque
+                        format=format
+                        on_change=on_change
+                    />
+                </Show>
+            </PickerPopover>
         </div>
     }
 }
@@ -1,12 +1,377 @@
+use crate::color_ext::{channel_from_255, channel_to_u8, ColorExt as _};
 use crate::components::alpha::Alpha;
-use crate::components::hue::Hue;
-use crate::theme::Theme;
+use crate::components::hue::{hue_to_position, Hue};
+#[cfg(feature = "lab")]
+use crate::components::lab_inputs::LabInputs;
+use crate::components::orientation::Orientation;
+use crate::components::slider::Slider;
+use crate::components::swatches::Swatches;
+use crate::contrast::{accessible_pair, readable_text_color};
+use crate::css_var::resolve_css_color;
+use crate::format::{
+    format_color, format_decimal, format_number, parse_color, parse_decimal, ColorFormat,
+};
+use crate::hooks::use_scrub::{use_scrub, UseScrubProps};
+use crate::linear::composite_linear;
+use crate::names::{name_of, named_color, NAMED_COLORS};
+use crate::theme::{use_theme, Theme};
 use crate::{components::saturation::Saturation, mount_style::mount_style};
 use csscolorparser::Color;
+use leptos::ev::KeyboardEvent;
 use leptos::html::Div;
 use leptos::logging::warn;
 use leptos::prelude::*;
 use leptos_use::{use_css_var_with_options, UseCssVarOptions};
+use web_sys::wasm_bindgen::JsCast as _;
+use web_sys::HtmlInputElement;
+
+/// Writes `text` to the system clipboard. Failures (e.g. a denied permission) are ignored.
+fn copy_to_clipboard(text: &str) {
+    _ = window().navigator().clipboard().write_text(text);
+}
+
+/// Keyboard semantics shared by the text inputs.
+///
+/// Enter commits the typed value by blurring the field (which fires its `change` handler),
+/// Escape restores the field to `current` without committing anything.
+pub(crate) fn handle_input_keydown(ev: &KeyboardEvent, current: impl FnOnce() -> String) {
+    let Some(input) = ev
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+    else {
+        return;
+    };
+    match ev.key().as_str() {
+        "Enter" => {
+            ev.prevent_default();
+            let _ = input.blur();
+        }
+        "Escape" => {
+            ev.prevent_default();
+            input.set_value(&current());
+        }
+        _ => {}
+    }
+}
+
+/// The color model the saturation area of a [`ColorPicker`] operates in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorModel {
+    /// The area maps to HSV saturation (horizontal) and value (vertical).
+    #[default]
+    Hsv,
+    /// The area maps to HSL saturation (horizontal) and lightness (vertical),
+    /// matching the model the hue slider uses.
+    Hsl,
+}
+
+/// Quantizes a `0..=1` alpha to the 8-bit steps shown in the alpha input, so the slider
+/// and the input always agree on the emitted value.
+fn quantize_alpha(alpha: f64) -> f32 {
+    channel_from_255(channel_to_u8(alpha as f32) as f32)
+}
+
+/// Quantizes a `0..=1` channel to the nearest of the `2^bits` levels of that bit depth, for
+/// `quantize_bits`. `bits` is clamped to `1..=16`; 8 bits yields the usual 256 levels.
+fn quantize_channel(value: f32, bits: u8) -> f32 {
+    let levels = ((1_u32 << bits.clamp(1, 16)) - 1) as f32;
+    (value.clamp(0.0, 1.0) * levels).round() / levels
+}
+
+/// Whether no channel of `color` is `NaN` or infinite, as produced by conversions with an
+/// undefined input, e.g. `Color::from_hsva` with a `NaN` hue.
+fn is_finite_color(color: &Color) -> bool {
+    [color.r, color.g, color.b, color.a]
+        .iter()
+        .all(|channel| channel.is_finite())
+}
+
+/// The values of the color dependent `--lpc-*` variables of a [`ColorPicker`] for one color.
+///
+/// The client keeps them updated through `use_css_var`; [`ColorVariables::to_style`] renders the
+/// initial ones into the container's `style` attribute, so server-rendered HTML already shows the
+/// handles and preview in place before hydration (or without JavaScript).
+struct ColorVariables {
+    hue: String,
+    red: String,
+    green: String,
+    blue: String,
+    hex: String,
+    alpha: String,
+    rgba: String,
+    hue_pointer: String,
+    alpha_pointer: String,
+    saturation_pointer_top: String,
+    saturation_pointer_left: String,
+}
+
+impl ColorVariables {
+    fn new(
+        c: &Color,
+        model: ColorModel,
+        (hue_min, hue_max): (f64, f64),
+        round_display: bool,
+        decimals: Option<u8>,
+    ) -> Self {
+        let hsla = c.to_hsla();
+        let hsva = c.to_hsva();
+        let rgba = c.to_rgba8();
+        let (hue, red, green, blue) = if round_display {
+            (
+                (hsla[0].round() as u16).to_string(),
+                rgba[0].to_string(),
+                rgba[1].to_string(),
+                rgba[2].to_string(),
+            )
+        } else {
+            // Stored with a `.`; `localized` applies the decimal separator for display.
+            (
+                format_number(hsla[0], decimals.unwrap_or(1), '.'),
+                format_number(c.r * 255.0, decimals.unwrap_or(2), '.'),
+                format_number(c.g * 255.0, decimals.unwrap_or(2), '.'),
+                format_number(c.b * 255.0, decimals.unwrap_or(2), '.'),
+            )
+        };
+        let hue_position = hue_to_position(hsla[0] as f64, hue_min, hue_max);
+        let (saturation, brightness) = match model {
+            ColorModel::Hsv => (hsva[1], hsva[2]),
+            ColorModel::Hsl => (hsla[1], hsla[2]),
+        };
+        Self {
+            hue,
+            red,
+            green,
+            blue,
+            hex: c.to_hex_string(),
+            alpha: rgba[3].to_string(),
+            rgba: c.to_rgba_css(),
+            hue_pointer: format!("{}%", (hue_position * 100.0).round()),
+            alpha_pointer: format!("{}%", (rgba[3] as f32 / 255.0 * 100.0).round()),
+            saturation_pointer_top: format!("calc({}% - 6px)", -(brightness * 100.0) + 100.0),
+            saturation_pointer_left: format!("calc({}% - 6px)", (saturation * 100.0).round()),
+        }
+    }
+
+    fn to_style(&self) -> String {
+        format!(
+            "--lpc-hue: {}; --lpc-red: {}; --lpc-green: {}; --lpc-blue: {}; --lpc-hex: {}; \
+             --lpc-alpha: {}; --lpc-rgba: {}; --lpc-hue-pointer: {}; --lpc-alpha-pointer: {}; \
+             --lpc-saturation-pointer-top: {}; --lpc-saturation-pointer-left: {};",
+            self.hue,
+            self.red,
+            self.green,
+            self.blue,
+            self.hex,
+            self.alpha,
+            self.rgba,
+            self.hue_pointer,
+            self.alpha_pointer,
+            self.saturation_pointer_top,
+            self.saturation_pointer_left,
+        )
+    }
+}
+
+/// The number of colors the undo history of a [`ColorPicker`] keeps.
+const UNDO_LIMIT: usize = 50;
+
+/// Whether consecutive changes from `source` belong to one continuous gesture, such as
+/// dragging a slider, and are undone as a single step.
+fn is_continuous(source: ChangeSource) -> bool {
+    matches!(
+        source,
+        ChangeSource::Saturation
+            | ChangeSource::Hue
+            | ChangeSource::Alpha
+            | ChangeSource::Lightness
+    )
+}
+
+/// A coarse user interaction with a [`ColorPicker`], reported by `on_interaction`.
+///
+/// Events describe which control was used, never the colors involved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractionEvent {
+    /// A drag (or keyboard adjustment) of the saturation area.
+    SaturationDrag,
+    /// A drag (or keyboard adjustment) of the hue slider.
+    HueDrag,
+    /// A drag (or keyboard adjustment) of the lightness slider shown with `grayscale_only`.
+    LightnessDrag,
+    /// A drag (or keyboard adjustment) of the alpha slider.
+    AlphaDrag,
+    /// A committed edit of the hex input.
+    HexEdit,
+    /// A committed edit or scrub of the red, green or blue inputs.
+    RgbEdit,
+    /// A committed edit of the hue input.
+    HueEdit,
+    /// A committed edit or scrub of the alpha input.
+    AlphaEdit,
+    /// A committed edit of the CIE Lab inputs.
+    LabEdit,
+    /// A choice from the named color dropdown.
+    NamedColorSelect,
+    /// A click on (or keyboard selection of) one of the `swatches` or `recent_colors`.
+    SwatchClick,
+    /// A color string dropped onto the preview swatch.
+    Drop,
+    /// The undo button or shortcut.
+    Undo,
+    /// The redo button or shortcut.
+    Redo,
+    /// The color was copied by clicking the preview.
+    Copy,
+    /// The CSS declaration was copied with the export button.
+    CssExport,
+}
+
+impl InteractionEvent {
+    /// The event for a color change from `source`, if it stems from the user.
+    fn from_source(source: ChangeSource) -> Option<Self> {
+        Some(match source {
+            ChangeSource::Saturation => Self::SaturationDrag,
+            ChangeSource::Hue => Self::HueDrag,
+            ChangeSource::Lightness => Self::LightnessDrag,
+            ChangeSource::Alpha => Self::AlphaDrag,
+            ChangeSource::HexInput => Self::HexEdit,
+            ChangeSource::RgbInput => Self::RgbEdit,
+            ChangeSource::HueInput => Self::HueEdit,
+            ChangeSource::AlphaInput => Self::AlphaEdit,
+            ChangeSource::LabInput => Self::LabEdit,
+            ChangeSource::NamedColor => Self::NamedColorSelect,
+            ChangeSource::Swatch => Self::SwatchClick,
+            ChangeSource::Drop => Self::Drop,
+            // Undo and redo are reported where they are triggered, programmatic changes not at all.
+            ChangeSource::History | ChangeSource::Programmatic => return None,
+        })
+    }
+}
+
+/// The arrangement of the controls inside a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PickerLayout {
+    /// The hue and alpha sliders are stacked below the saturation area.
+    #[default]
+    Stacked,
+    /// The alpha slider runs vertically to the right of the saturation area,
+    /// the hue slider stays below it.
+    SidebarAlpha,
+    /// A compact single row for toolbars: the saturation area is omitted and the preview,
+    /// the hue and alpha sliders and the hex input sit side by side.
+    Row,
+    /// Only the `swatches` and `recent_colors` are shown, for pickers that must constrain the
+    /// choice to a palette: the saturation area, sliders, preview and inputs are omitted.
+    SwatchesOnly,
+}
+
+/// Where a [`ColorPicker`] places its numeric inputs relative to the sliders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputsPosition {
+    /// The inputs form a row beneath the sliders.
+    #[default]
+    Below,
+    /// The inputs form a column to the right of the saturation area, preview and sliders,
+    /// for pickers that are wider than tall.
+    Side,
+}
+
+/// The element of a [`ColorPicker`] that receives focus when it is mounted, see `autofocus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoFocusTarget {
+    /// Focus is left where it is.
+    #[default]
+    None,
+    /// The hex input, with its text selected so it can be typed over.
+    Hex,
+    /// The saturation area.
+    Saturation,
+}
+
+/// Debounces commits of a text input while the user types.
+///
+/// The returned `schedule` callback runs `commit` with the typed value once `delay_ms` pass
+/// without another call; `cancel` drops a pending commit, e.g. because the field commits
+/// immediately on blur. Without a delay, `schedule` does nothing.
+fn use_typing_commit(
+    delay_ms: MaybeProp<u32>,
+    commit: Callback<String>,
+) -> (Callback<String>, Callback<()>) {
+    let pending = StoredValue::new(None::<TimeoutHandle>);
+    let cancel = Callback::new(move |()| {
+        if let Some(handle) = pending.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    });
+    let schedule = Callback::new(move |value: String| {
+        cancel.run(());
+        let Some(delay_ms) = delay_ms.get_untracked() else {
+            return;
+        };
+        let handle = set_timeout_with_handle(
+            move || {
+                pending.set_value(None);
+                commit.run(value);
+            },
+            std::time::Duration::from_millis(delay_ms.into()),
+        )
+        .ok();
+        pending.set_value(handle);
+    });
+    on_cleanup(move || cancel.run(()));
+    (schedule, cancel)
+}
+
+/// The control a color change originated from, reported by `on_change_detailed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeSource {
+    /// The saturation/value area.
+    Saturation,
+    /// The hue slider.
+    Hue,
+    /// The alpha slider.
+    Alpha,
+    /// The lightness slider shown with `grayscale_only`.
+    Lightness,
+    /// The hexadecimal input.
+    HexInput,
+    /// One of the red, green or blue inputs.
+    RgbInput,
+    /// The numeric hue input.
+    HueInput,
+    /// The alpha input.
+    AlphaInput,
+    /// One of the CIE Lab inputs.
+    LabInput,
+    /// The named color dropdown shown with `show_named_dropdown`.
+    NamedColor,
+    /// One of the `swatches` or `recent_colors`.
+    Swatch,
+    /// A color string dropped onto the preview swatch.
+    Drop,
+    /// A call to [`PickerHandle::set`], or the resolved `initial_css_color`.
+    Programmatic,
+    /// The undo or redo buttons, or their keyboard shortcuts.
+    History,
+}
+
+/// A handle for driving a [`ColorPicker`] imperatively, passed to its `on_ready` callback.
+#[derive(Clone, Copy)]
+pub struct PickerHandle {
+    emit: Callback<(Color, ChangeSource)>,
+}
+
+impl PickerHandle {
+    /// Sets the picker to `color`.
+    ///
+    /// The picker is controlled through its `color` signal, so this runs the picker's
+    /// `on_change` callback (including its normalization, e.g. `force_opaque`) and lets
+    /// the owner of the signal apply the new value.
+    pub fn set(&self, color: Color) {
+        self.emit.run((color, ChangeSource::Programmatic));
+    }
+}
+
 /// A comprehensive color picker component.
 ///
 /// This component provides a full-featured color picker with saturation/value selection,
@@ -14,12 +379,175 @@ use leptos_use::{use_css_var_with_options, UseCssVarOptions};
 ///
 /// # Props
 ///
-/// * `theme`: A `MaybeSignal<Theme>` representing the theme for the component. Defaults to `Theme::default()`.
+/// * `theme`: An optional `Signal<Theme>` representing the theme for the component. Falls back to the
+///   theme of the nearest `ThemeProvider`, then to `Theme::default()`.
 /// * `color`: A `Signal<Color>` representing the current color value.
 /// * `hide_alpha`: An optional `MaybeSignal<bool>` to hide the alpha channel controls.
 /// * `hide_hex`: An optional `MaybeSignal<bool>` to hide the hexadecimal color input.
 /// * `hide_rgb`: An optional `MaybeSignal<bool>` to hide the RGB color inputs.
+/// * `hue_min`: The smallest selectable hue in degrees. Defaults to `0`.
+/// * `hue_max`: The largest selectable hue in degrees. Defaults to `360`. Ranges wrap around
+///   when `hue_max` is smaller than `hue_min`, e.g. `330` to `30`.
+/// * `layout`: An optional `Signal<PickerLayout>` controlling the arrangement of the controls.
+///   Defaults to `PickerLayout::Stacked`. `PickerLayout::Row` fits the picker into a toolbar.
+///   `PickerLayout::SwatchesOnly` renders only the swatches, so nothing outside the palette can be
+///   picked or typed: the controls, inputs, named dropdown, undo history and CSS export are not
+///   mounted. Theming and callbacks stay the same.
+/// * `swatches`: An optional `Signal<Vec<Color>>` with a palette shown as [`Swatches`] below the inputs.
+///   Selecting one emits it with `ChangeSource::Swatch`; the swatch matching the color is marked. The
+///   swatches are keyboard navigable with the arrow keys, Home and End.
+/// * `recent_colors`: An optional `Signal<Vec<Color>>` shown as a second, scrollable row of swatches,
+///   e.g. the colors the app recorded from `on_change`. Behaves like `swatches`.
+/// * `css_swatches`: An optional `Signal<Vec<String>>` with more swatches given as CSS colors, including
+///   design-token references such as `var(--brand-primary)` or `var(--brand-primary, #3b82f6)`. They
+///   are resolved against the picker with [`resolve_css_color`] once it is mounted, and shown after
+///   `swatches`. Values that do not resolve to a color are left out.
+/// * `initial_css_color`: An optional CSS color, e.g. `var(--brand-primary)`, resolved like
+///   `css_swatches` once the picker is mounted and emitted with `ChangeSource::Programmatic`. Nothing
+///   is emitted if it does not resolve to a color.
+/// * `inputs_position`: An optional `Signal<InputsPosition>` placing the hex, RGB and alpha inputs
+///   beneath the sliders (`InputsPosition::Below`, the default) or in a column beside them
+///   (`InputsPosition::Side`). Ignored by `PickerLayout::Row`, which always keeps them inline.
+/// * `max_height`: An optional `Signal<Option<String>>` with a CSS length, e.g. `"320px"` or `"60vh"`,
+///   capping the picker's height. Taller pickers scroll internally: the saturation area, preview and
+///   sliders stay in place at the top while the inputs, dropdown and buttons scroll beneath them.
+///   Controls that receive keyboard focus are scrolled into the visible part.
+/// * `internal_model`: An optional `Signal<ColorModel>` selecting whether the saturation area edits
+///   HSV saturation/value (`ColorModel::Hsv`, the default) or HSL saturation/lightness (`ColorModel::Hsl`).
+/// * `lock_hue`, `lock_alpha`, `lock_saturation`: Optional `Signal<bool>`s that keep the respective
+///   control visible but ignore pointer input. Unlike `hide_*`, the current value stays on display.
+/// * `saturation_border`: An optional `Signal<bool>`, `true` by default, drawing a thin inner border in the
+///   theme's border color around the saturation area so near-white colors stay distinguishable from a
+///   light page. Set it to `false` for a borderless area.
+/// * `snap_resolution`: An optional `Signal<Option<u32>>` snapping the saturation area to a grid of that
+///   many steps per axis (e.g. `Some(256)`) to reduce the number of distinct colors emitted while
+///   dragging on slow devices, at the cost of precision. Defaults to `None` (continuous).
+/// * `touch_offset`: An optional `Signal<bool>`. When set, touch drags on the saturation area select a
+///   point slightly above the finger, so the handle stays visible while dragging. Mouse input is not
+///   affected.
+/// * `value_epsilon`: An optional `Signal<f32>`, `0.001` by default. The smallest saturation and
+///   value (or lightness) the saturation area emits, see the behavior notes below. Set it to `0.0`
+///   to emit exact extremes.
+/// * `grayscale_only`: An optional `Signal<bool>` restricting the picker to neutral grays. The saturation
+///   area, hue slider and hue input are replaced by a single black-to-white lightness slider, and every
+///   emitted color has its saturation forced to `0` (keeping its HSL lightness and alpha). The lightness
+///   slider is labelled "Lightness" and operable with the keyboard like the hue and alpha sliders.
+/// * `quantize_bits`: An optional `Signal<Option<u8>>`. With `Some(bits)`, the red, green and blue
+///   channels of every emitted color are quantized to that bit depth (rounded to the nearest level), e.g.
+///   `Some(4)` for 12-bit colors such as `#8af`. Alpha is left as is. Since the picker shows the color it
+///   is given, the inputs show the quantized color once the owner stores it.
+/// * `force_opaque`: An optional `Signal<bool>` that forces the alpha of every emitted color to `1.0`
+///   and hides the alpha controls. Unlike `hide_alpha`, this also discards alpha coming from
+///   an 8-digit hex value.
+/// * `strip_alpha_on_output`: An optional `Signal<bool>` that forces the alpha of every emitted color to
+///   `1.0` while keeping the alpha controls. The picker previews the alpha the user picked (starting
+///   fully opaque), but only opaque colors are committed.
+/// * `hide_lab`: An optional `Signal<bool>` to hide the CIE Lab (D65) inputs. Defaults to `true`.
+///   Requires the `lab` feature; without it the inputs are compiled out and the prop has no effect.
+///   Lab values outside of the sRGB gamut are clamped per channel, and a warning icon
+///   ("clamped to sRGB") is shown next to the inputs until the color is changed by another control.
+/// * `show_hue_input`: An optional `Signal<bool>` that shows a numeric hue input (0–360°).
+///   Values outside of that range wrap around, e.g. `361` becomes `1`.
+/// * `scrubbable_inputs`: An optional `Signal<bool>` that lets users drag horizontally over the
+///   RGB and alpha inputs to scrub their values. Off by default so text selection keeps working.
+/// * `format`: An optional `Signal<ColorFormat>` used when the picker presents the color as a single
+///   string, e.g. the tooltip of the preview swatch. Defaults to `ColorFormat::Hex`.
+/// * `alpha_checkerboard`: An optional `Signal<bool>`, `true` by default. When `false`, the alpha slider
+///   shows only the color-to-transparent gradient over the theme background, without a checkerboard.
+/// * `show_hue_label`: An optional `Signal<bool>` that shows the current hue in degrees (e.g. `210°`)
+///   below the hue slider. Hidden with `grayscale_only`, which has no hue slider.
+/// * `show_alpha_label`: An optional `Signal<bool>` that shows the current alpha as a percentage
+///   below the alpha slider.
+/// * `linear_alpha_preview`: An optional `Signal<bool>`. When set, the preview swatch composites the color
+///   over its checkerboard in linear light (computed in Rust) instead of letting the browser blend in
+///   gamma-encoded sRGB, which renders translucent colors too dark.
+/// * `preview_checkerboard`: An optional `Signal<bool>`, `true` by default. When `false`, the preview swatch
+///   shows the color without the checkerboard behind it. It is always hidden with `force_opaque`, where
+///   transparency cannot occur.
+/// * `preview_clickable`: An optional `Signal<bool>`. When set, clicking the preview swatch (or pressing
+///   Enter/Space while it is focused) copies the color, formatted with `format`, to the clipboard and
+///   briefly shows `copied_label` as feedback.
+/// * `show_named_dropdown`: An optional `Signal<bool>` that shows a dropdown of the CSS named colors,
+///   each option drawn in its color. Choosing one emits it (opaque) with `ChangeSource::NamedColor`.
+///   The dropdown shows the name while the color exactly matches a named color, and a placeholder
+///   otherwise.
+/// * `show_css_export`: An optional `Signal<bool>` that shows a button copying a CSS declaration such as
+///   `color: #3278c8;` to the clipboard, with the value formatted with `format`. It briefly shows
+///   `copied_label` as feedback.
+/// * `css_export_label`: An optional `Signal<String>` with the text of that button. Defaults to `"Copy CSS"`.
+/// * `copy_label`: An optional `Signal<String>` prefixing the accessible name of the clickable preview,
+///   followed by the formatted color. Defaults to `"Copy color"`.
+/// * `copied_label`: An optional `Signal<String>` shown as the copy feedback for 1.2 seconds, after which
+///   the preview returns to showing the color. Defaults to `"Copied"`.
+/// * `large_value`: An optional `Signal<bool>` that shows the current color, formatted with `format`, as
+///   a large label filled with the color, e.g. for projected demos. The text is black or white,
+///   whichever contrasts more with the color (see [`readable_text_color`]). Its font can be set with
+///   the `--lpc-font-family` and `--lpc-large-value-font-size` CSS variables.
+/// * `text_suggestion_ratio`: An optional `MaybeProp<f64>` with a minimum contrast ratio, e.g. `4.5` for
+///   WCAG AA body text. When set, the picker suggests a foreground for the selected color as a
+///   background: a sample and the hex of [`accessible_pair`]'s result, which keeps the color's tint
+///   where the ratio allows. `large_value` then uses that color for its text as well.
+/// * `announce_changes`: An optional `Signal<bool>`, `true` by default. Announces the current color
+///   (e.g. "Hue 210 degrees, #3278c8") to screen readers through a visually hidden `aria-live` region.
+///   Announcements are debounced, so dragging a slider only announces where it comes to rest.
+/// * `round_display`: An optional `Signal<bool>`, `true` by default. Shows the hue and RGB inputs as
+///   rounded integers so they do not jitter mid-drag. When `false`, they show fractional values. This
+///   only affects what is displayed; emitted colors keep their full precision.
+/// * `hue_preview_at_sv`: An optional `Signal<bool>`. When set, the hue slider previews every hue at the
+///   current saturation and value instead of showing the fully saturated rainbow.
+/// * `decimal_separator`: An optional `Signal<char>` used by the inputs that accept fractional values
+///   (hue, RGB when `round_display` is off, and Lab), e.g. `','` for locales that write `0,5`. Defaults
+///   to `'.'`. With any other separator these inputs become text fields, since number fields only
+///   accept `.`.
+/// * `value_decimals`: An optional `MaybeProp<u8>` setting the number of decimals shown by the inputs
+///   that accept fractional values. Without it, the hue shows 1 decimal, the RGB channels 2 (both only
+///   when `round_display` is off) and Lab 0. Numbers are never written with grouping separators.
+/// * `valid`: An optional `RwSignal<bool>` the picker keeps updated with the result of the last text input
+///   parse: `false` after a value that could not be parsed was entered into the hex, RGB, hue or alpha
+///   inputs, `true` again once a valid value is committed or the input is reverted with Escape. Slider
+///   and saturation interactions always produce valid colors and do not change it.
+/// * `hex_with_hash`: An optional `Signal<bool>`. When set, the hex input holds the value including its
+///   leading `#` instead of showing it as a separate prefix, so copying the field yields a ready-to-paste
+///   hex color. Typed values are accepted with or without the `#` either way.
+/// * `commit_delay_ms`: An optional delay in milliseconds. When set, values typed into the hex, RGB and
+///   hue inputs are also committed once the user pauses typing for that long, as long as they parse. Without
+///   it, typed values are only committed on Enter, change or blur. Blurring a field always commits
+///   immediately and cancels a pending delayed commit.
+/// * `transform`: An optional `Callback<Color, Color>` applied to every candidate color before it is
+///   emitted, e.g. to snap to a brand palette or enforce a minimum contrast. The returned color is what
+///   `on_change` receives and, once the owner stores it, what the picker shows. It runs after the
+///   controls clamp their values and quantize alpha, and before `force_opaque` is applied, so
+///   `force_opaque` always holds.
+/// * `show_undo`: An optional `Signal<bool>` that shows undo and redo buttons. While set, the picker
+///   records every color it emits (up to 50) and Ctrl+Z / Ctrl+Y (or Ctrl+Shift+Z) inside the picker
+///   step through them, outside of text fields where those keys edit the text. A drag of a slider or
+///   the saturation area is undone as one step. Undoing emits the previous color through `on_change`
+///   with `ChangeSource::History`.
+/// * `autofocus`: An optional `Signal<AutoFocusTarget>` selecting the element that is focused once the
+///   picker is mounted, e.g. when it opens in a `ColorInput` popover, so keyboard users can start right
+///   away. Defaults to `AutoFocusTarget::None`, which never moves focus. Nothing is focused when the
+///   target is hidden.
 /// * `on_change`: A `Callback<Color>` that is called when the color value changes.
+/// * `on_change_detailed`: An optional `Callback<(Color, ChangeSource)>` called alongside `on_change`
+///   with the control the change originated from.
+/// * `on_change_premul`: An optional `Callback<[f32; 4]>` called alongside `on_change` with the color as
+///   premultiplied-alpha RGBA (`[r * a, g * a, b * a, a]`) for WebGL and canvas consumers.
+/// * `on_change_raw`: An optional `Callback<[f32; 4]>` called alongside `on_change` with the RGBA channels
+///   the control computed, before gamut clamping and before `transform`, `grayscale_only`,
+///   `force_opaque` and `strip_alpha_on_output` are applied. For Lab input outside of the sRGB gamut the
+///   channels fall outside of `[0, 1]`; such values are not valid sRGB and are meant for apps doing
+///   their own gamut mapping, e.g. for wide-gamut or HDR experiments.
+/// * `on_interaction`: An optional `Callback<InteractionEvent>` for usage analytics. It reports which
+///   control was used, once per interaction rather than per emitted color: a drag or scrub is reported
+///   when it starts, and repeated edits of the same control are reported once until the pointer goes
+///   down or focus moves to another control. Copying, exporting, undo and redo are reported per click
+///   or shortcut. The events carry no colors or typed text, so they contain nothing the user entered;
+///   apps forwarding them to a telemetry service remain responsible for asking for consent.
+/// * `on_init`: An optional `Callback<Color>` called exactly once, after the CSS variables have been set
+///   for the initial color, with that color (normalized like emitted colors, e.g. by `force_opaque`).
+///   Useful to start animations or measurements that depend on the picker showing its color.
+/// * `on_ready`: An optional `Callback<PickerHandle>` called once after the picker is mounted with a
+///   handle for setting the color imperatively, e.g. when pasting from a menu.
 ///
 /// # Features
 ///
@@ -33,9 +561,41 @@ use leptos_use::{use_css_var_with_options, UseCssVarOptions};
 /// # Behavior
 ///
 /// - The component uses CSS variables to manage and update color values efficiently.
+/// - The initial color's `--lpc-*` variables (pointer positions, preview color) are rendered into the
+///   container's `style` attribute, so server-rendered HTML shows the handles in place before it hydrates.
+/// - Changing the `theme` signal at runtime (e.g. toggling light and dark) re-applies all `--lpc-*`
+///   theme variables in place, without remounting and without touching the color variables.
 /// - It reacts to changes in the `color` signal and updates all UI elements accordingly.
 /// - User interactions with any part of the color picker (saturation area, hue slider, alpha slider, or input fields)
 ///   trigger the `on_change` callback with the updated color.
+/// - A single color change results in one reactive flush: all CSS variables are written in one
+///   effect run, and effects depending on them are scheduled together afterwards.
+/// - In the saturation area, saturation and value are kept at `value_epsilon` (`0.001`) or more so
+///   the hue survives when dragging into the gray and black edges: a color with zero saturation or
+///   value has no hue, so the hue slider would jump back to red. At 8-bit resolution the corners still
+///   yield exactly white, the pure hue and black. With `value_epsilon` set to `0.0` the emitted
+///   colors reach the exact extremes, and the hue is lost along those edges.
+/// - Colors with a `NaN` or infinite channel, e.g. from a degenerate conversion or `transform`, are never
+///   emitted: the current color is emitted in their place. With the `debug_logging` feature, a warning
+///   is logged to the console when that happens.
+/// - The alpha props combine as follows:
+///
+///   | Prop                    | Alpha controls | Preview alpha | Emitted alpha                 |
+///   |-------------------------|----------------|---------------|-------------------------------|
+///   | none                    | shown          | as picked     | as picked                     |
+///   | `hide_alpha`            | hidden         | from `color`  | kept, even for an 8-digit hex |
+///   | `force_opaque`          | hidden         | `1.0`         | `1.0`                         |
+///   | `strip_alpha_on_output` | shown          | as picked     | `1.0`                         |
+///
+///   `force_opaque` wins over the others.
+/// - Alpha is always emitted in 8-bit steps, so the slider and the alpha input produce identical values.
+/// - Dropping text onto the preview swatch (e.g. a dragged `Swatches` entry) sets the color if the text
+///   is a valid color in any format understood by `parse_color`. Other drops are ignored.
+/// - While the alpha controls are hidden, the alpha of an 8-digit hex value entered into the hex input
+///   is ignored and the current alpha is kept, so the hidden channel never changes unnoticed.
+/// - In the input fields, Enter commits the typed value and Escape reverts it to the current color.
+/// - The RGB and Lab inputs are grouped in `fieldset`s with visually hidden legends for assistive
+///   technology. The tab order follows the visual order of the controls.
 ///
 /// # Example
 ///
@@ -60,15 +620,190 @@ use leptos_use::{use_css_var_with_options, UseCssVarOptions};
 /// when any change is made in the picker.
 #[component]
 pub fn ColorPicker(
-    #[prop(into, default=Theme::default().into())] theme: Signal<Theme>,
+    #[prop(into, optional)] theme: Option<Signal<Theme>>,
     #[prop(into)] color: Signal<Color>,
     #[prop(into, optional)] hide_alpha: Signal<bool>,
     #[prop(into, optional)] hide_hex: Signal<bool>,
     #[prop(into, optional)] hide_rgb: Signal<bool>,
+    #[prop(into, default = true.into())] hide_lab: Signal<bool>,
+    #[prop(into, default = 0.0.into())] hue_min: Signal<f64>,
+    #[prop(into, default = 360.0.into())] hue_max: Signal<f64>,
+    #[prop(into, optional)] layout: Signal<PickerLayout>,
+    #[prop(into, optional)] swatches: Signal<Vec<Color>>,
+    #[prop(into, optional)] recent_colors: Signal<Vec<Color>>,
+    #[prop(into, optional)] css_swatches: Signal<Vec<String>>,
+    #[prop(into, optional)] initial_css_color: MaybeProp<String>,
+    #[prop(into, optional)] inputs_position: Signal<InputsPosition>,
+    #[prop(into, optional)] max_height: Signal<Option<String>>,
+    #[prop(into, optional)] internal_model: Signal<ColorModel>,
+    #[prop(into, optional)] lock_hue: Signal<bool>,
+    #[prop(into, optional)] lock_alpha: Signal<bool>,
+    #[prop(into, optional)] lock_saturation: Signal<bool>,
+    #[prop(into, default = true.into())] saturation_border: Signal<bool>,
+    #[prop(into, optional)] snap_resolution: Signal<Option<u32>>,
+    #[prop(into, default = 0.001.into())] value_epsilon: Signal<f32>,
+    #[prop(into, optional)] touch_offset: Signal<bool>,
+    #[prop(into, optional)] grayscale_only: Signal<bool>,
+    #[prop(into, optional)] quantize_bits: Signal<Option<u8>>,
+    #[prop(into, optional)] force_opaque: Signal<bool>,
+    #[prop(into, optional)] strip_alpha_on_output: Signal<bool>,
+    #[prop(into, optional)] show_hue_input: Signal<bool>,
+    #[prop(into, optional)] scrubbable_inputs: Signal<bool>,
+    #[prop(into, optional)] format: Signal<ColorFormat>,
+    #[prop(into, default = true.into())] alpha_checkerboard: Signal<bool>,
+    #[prop(into, optional)] show_hue_label: Signal<bool>,
+    #[prop(into, optional)] show_alpha_label: Signal<bool>,
+    #[prop(into, optional)] linear_alpha_preview: Signal<bool>,
+    #[prop(into, default = true.into())] preview_checkerboard: Signal<bool>,
+    #[prop(into, optional)] preview_clickable: Signal<bool>,
+    #[prop(into, optional)] show_named_dropdown: Signal<bool>,
+    #[prop(into, optional)] show_css_export: Signal<bool>,
+    #[prop(into, default = "Copy CSS".to_string().into())] css_export_label: Signal<String>,
+    #[prop(into, default = "Copy color".to_string().into())] copy_label: Signal<String>,
+    #[prop(into, default = "Copied".to_string().into())] copied_label: Signal<String>,
+    #[prop(into, optional)] large_value: Signal<bool>,
+    #[prop(into, optional)] text_suggestion_ratio: MaybeProp<f64>,
+    #[prop(into, default = true.into())] announce_changes: Signal<bool>,
+    #[prop(into, default = true.into())] round_display: Signal<bool>,
+    #[prop(into, optional)] hue_preview_at_sv: Signal<bool>,
+    #[prop(into, default = '.'.into())] decimal_separator: Signal<char>,
+    #[prop(into, optional)] value_decimals: MaybeProp<u8>,
+    #[prop(optional)] valid: Option<RwSignal<bool>>,
+    #[prop(into, optional)] hex_with_hash: Signal<bool>,
+    #[prop(into, optional)] commit_delay_ms: MaybeProp<u32>,
+    #[prop(into, optional)] show_undo: Signal<bool>,
+    #[prop(into, optional)] autofocus: Signal<AutoFocusTarget>,
+    #[prop(into, optional)] transform: Option<Callback<Color, Color>>,
     #[prop(into)] on_change: Callback<Color>,
+    #[prop(into, optional)] on_change_detailed: Option<Callback<(Color, ChangeSource)>>,
+    #[prop(into, optional)] on_change_premul: Option<Callback<[f32; 4]>>,
+    #[prop(into, optional)] on_change_raw: Option<Callback<[f32; 4]>>,
+    #[prop(into, optional)] on_interaction: Option<Callback<InteractionEvent>>,
+    #[prop(into, optional)] on_init: Option<Callback<Color>>,
+    #[prop(into, optional)] on_ready: Option<Callback<PickerHandle>>,
 ) -> impl IntoView {
     mount_style("ColorPicker", include_str!("./color_picker.css"));
+    let theme = use_theme(theme);
+    let gamut_clamped = RwSignal::new(false);
+    let undo_stack = RwSignal::new(Vec::<Color>::new());
+    let redo_stack = RwSignal::new(Vec::<Color>::new());
+    // The source of the last recorded change, cleared when a new gesture starts.
+    let last_recorded = StoredValue::new(None::<ChangeSource>);
+    // The source last reported to `on_interaction`, cleared like `last_recorded` and on focus moves.
+    let last_interaction = StoredValue::new(None::<ChangeSource>);
+    let report_interaction = move |event: InteractionEvent| {
+        if let Some(on_interaction) = on_interaction {
+            on_interaction.run(event);
+        }
+    };
+    let current = color;
+    let previewed_alpha = RwSignal::new(1.0_f32);
+    // Set by controls that clamp the channels they computed, e.g. the Lab inputs.
+    let raw_channels = StoredValue::new(None::<[f32; 4]>);
+    let emit = Callback::new(move |(mut color, source): (Color, ChangeSource)| {
+        let raw = raw_channels
+            .try_update_value(Option::take)
+            .flatten()
+            .unwrap_or([color.r, color.g, color.b, color.a]);
+        if source != ChangeSource::LabInput {
+            gamut_clamped.set(false);
+        }
+        if let Some(transform) = transform {
+            color = transform.run(color);
+        }
+        if grayscale_only.get_untracked() {
+            let lightness = color.to_hsla()[2];
+            color = Color::new(lightness, lightness, lightness, color.a);
+        }
+        // A degenerate conversion (or `transform`) must not leak `NaN` channels to the owner or
+        // the preview, so this runs before any step that stores or reshapes the channels.
+        if !is_finite_color(&color) {
+            #[cfg(feature = "debug_logging")]
+            warn!("leptos_color: discarded a non-finite color {color:?} from {source:?}");
+            color = current.get_untracked();
+            if strip_alpha_on_output.get_untracked() {
+                color.a = previewed_alpha.get_untracked();
+            }
+            if !is_finite_color(&color) {
+                return;
+            }
+        }
+        if let Some(bits) = quantize_bits.get_untracked() {
+            color.r = quantize_channel(color.r, bits);
+            color.g = quantize_channel(color.g, bits);
+            color.b = quantize_channel(color.b, bits);
+        }
+        if force_opaque.get_untracked() {
+            color.a = 1.0;
+        }
+        if strip_alpha_on_output.get_untracked() {
+            previewed_alpha.set(color.a);
+            color.a = 1.0;
+        }
+        if source != ChangeSource::History && show_undo.get_untracked() {
+            let previous = current.get_untracked();
+            let coalesce = is_continuous(source) && last_recorded.get_value() == Some(source);
+            if !coalesce && previous != color {
+                undo_stack.update(|stack| {
+                    stack.push(previous);
+                    if stack.len() > UNDO_LIMIT {
+                        stack.remove(0);
+                    }
+                });
+                redo_stack.update(Vec::clear);
+                last_recorded.set_value(Some(source));
+            }
+        }
+        on_change.run(color);
+        if let Some(on_change_detailed) = on_change_detailed {
+            on_change_detailed.run((color, source));
+        }
+        if let Some(on_change_premul) = on_change_premul {
+            on_change_premul.run(color.to_premultiplied());
+        }
+        if let Some(on_change_raw) = on_change_raw {
+            on_change_raw.run(raw);
+        }
+        if let Some(event) = InteractionEvent::from_source(source) {
+            if last_interaction.get_value() != Some(source) {
+                last_interaction.set_value(Some(source));
+                report_interaction(event);
+            }
+        }
+    });
+    let hide_alpha = Signal::derive(move || hide_alpha.get() || force_opaque.get());
+    // With `strip_alpha_on_output` the controls show the alpha the user picked, not the emitted one.
+    let color = Signal::derive(move || {
+        let mut color = color.get();
+        if strip_alpha_on_output.get() {
+            color.a = previewed_alpha.get();
+        }
+        color
+    });
+    if let Some(on_ready) = on_ready {
+        Effect::new(move |_| on_ready.run(PickerHandle { emit }));
+    }
     let el = NodeRef::<Div>::new();
+    let hex_ref = NodeRef::<leptos::html::Input>::new();
+    // Runs once, after the picker is mounted.
+    Effect::new(move |_| match autofocus.get_untracked() {
+        AutoFocusTarget::None => {}
+        AutoFocusTarget::Hex => {
+            if let Some(input) = hex_ref.get_untracked() {
+                _ = input.focus();
+                input.select();
+            }
+        }
+        AutoFocusTarget::Saturation => {
+            if let Some(area) = el
+                .get_untracked()
+                .and_then(|el| el.query_selector(".leptos-color-color").ok().flatten())
+                .and_then(|area| area.dyn_into::<web_sys::HtmlElement>().ok())
+            {
+                _ = area.focus();
+            }
+        }
+    });
     let (hue, set_hue) = use_css_var_with_options(
         "--lpc-hue",
         UseCssVarOptions::default()
@@ -158,98 +893,615 @@ pub fn ColorPicker(
             .observe(false),
     );
 
-    // React to color changes and update CSS variables
+    // Apply theme changes one variable at a time. Replacing the whole `style` attribute would
+    // drop the color variables written by `use_css_var`.
     Effect::new(move |_| {
-        color.track();
-        let hsla = color.get().to_hsla();
-        let rgba = color.get().to_rgba8();
-        let alpha = rgba[3];
-        let hex = color.get().to_hex_string();
-        let hsva = color.get().to_hsva();
-
-        set_hue.set((hsla[0] as u16).to_string());
-        set_red.set(rgba[0].to_string());
-        set_green.set(rgba[1].to_string());
-        set_blue.set(rgba[2].to_string());
-        set_hex.set(hex);
-        set_alpha.set(alpha.to_string());
-        set_rgba.set(format!(
-            "rgba({}, {}, {}, {})",
-            rgba[0],
-            rgba[1],
-            rgba[2],
-            (alpha as f32 / 255.0)
+        let style = theme.with(|value| value.to_style());
+        let Some(el) = el.get() else {
+            return;
+        };
+        for (property, value) in style.split(';').filter_map(|d| d.split_once(':')) {
+            _ = el.style().set_property(property.trim(), value.trim());
+        }
+    });
+
+    // React to color changes and update CSS variables.
+    // Leptos 0.7 schedules effects instead of running them synchronously, so the many
+    // `set_*` calls below (and any signal updates done by `on_change` consumers) are
+    // already coalesced into a single reactive flush; there is no `batch` to wrap them in.
+    // The stress example counts the effect runs per emitted color to check this.
+    let color_variables = move || {
+        color.with(|c| {
+            ColorVariables::new(
+                c,
+                internal_model.get(),
+                (hue_min.get(), hue_max.get()),
+                round_display.get(),
+                value_decimals.get(),
+            )
+        })
+    };
+    Effect::new(move |previous_run: Option<()>| {
+        let c = color.get();
+        let variables = color_variables();
+        set_hue.set(variables.hue);
+        set_red.set(variables.red);
+        set_green.set(variables.green);
+        set_blue.set(variables.blue);
+        set_hex.set(variables.hex);
+        set_alpha.set(variables.alpha);
+        set_rgba.set(variables.rgba);
+        set_hue_pointer.set(variables.hue_pointer);
+        set_alpha_pointer.set(variables.alpha_pointer);
+        set_saturation_pointer_top.set(variables.saturation_pointer_top);
+        set_saturation_pointer_left.set(variables.saturation_pointer_left);
+
+        if let (None, Some(on_init)) = (previous_run, on_init) {
+            let mut c = c;
+            if force_opaque.get_untracked() || strip_alpha_on_output.get_untracked() {
+                c.a = 1.0;
+            }
+            on_init.run(c);
+        }
+    });
+
+    // Scrub handlers for the 8-bit channel inputs
+    let scrub_channel = move |channel: fn(&mut Color) -> &mut f32, source: ChangeSource| {
+        use_scrub(UseScrubProps {
+            enabled: scrubbable_inputs,
+            on_scrub: Callback::new(move |steps: i32| {
+                let mut color = color.get_untracked();
+                let value = channel(&mut color);
+                *value = channel_from_255(channel_to_u8(*value) as f32 + steps as f32);
+                emit.run((color, source));
+            }),
+        })
+    };
+    let scrub_red = scrub_channel(|color| &mut color.r, ChangeSource::RgbInput);
+    let scrub_green = scrub_channel(|color| &mut color.g, ChangeSource::RgbInput);
+    let scrub_blue = scrub_channel(|color| &mut color.b, ChangeSource::RgbInput);
+    let scrub_alpha = scrub_channel(|color| &mut color.a, ChangeSource::AlphaInput);
+
+    let show_preview_checkerboard = move || preview_checkerboard.get() && !force_opaque.get();
+    let preview_style = move || {
+        if !linear_alpha_preview.get() || !show_preview_checkerboard() {
+            return String::new();
+        }
+        let (dark, light) = color.with(|c| {
+            (
+                composite_linear(c, &Color::new(0.5, 0.5, 0.5, 1.0)),
+                composite_linear(c, &Color::new(1.0, 1.0, 1.0, 1.0)),
+            )
+        });
+        format!(
+            "background: repeating-conic-gradient({} 0% 25%, {} 0% 50%) 50% / 10px 10px;",
+            dark.to_hex_string(),
+            light.to_hex_string()
+        )
+    };
+
+    #[cfg(feature = "lab")]
+    let lab_inputs = move || {
+        view! {
+            <LabInputs
+                color=color
+                emit=emit
+                gamut_clamped=gamut_clamped
+                raw_channels=raw_channels
+                decimal_separator=decimal_separator
+                value_decimals=value_decimals
+            />
+        }
+    };
+    #[cfg(not(feature = "lab"))]
+    let lab_inputs = || ();
+
+    let hex_invalid = RwSignal::new(false);
+    let set_valid = move |is_valid: bool| {
+        if let Some(valid) = valid {
+            valid.set(is_valid);
+        }
+    };
+    // Invalid values are only reported when `report_invalid` is set, so a delayed commit of
+    // half-typed text does not flag the field.
+    let commit_hex = move |value: String, report_invalid: bool| match value.parse::<Color>() {
+        Ok(mut new_color) => {
+            // The alpha cannot be seen or edited while hidden, so a pasted 8-digit hex keeps it.
+            if hide_alpha.get_untracked() {
+                new_color.a = color.with_untracked(|c| c.a);
+            }
+            hex_invalid.set(false);
+            set_valid(true);
+            emit.run((new_color, ChangeSource::HexInput));
+        }
+        Err(_) if report_invalid => {
+            hex_invalid.set(true);
+            set_valid(false);
+        }
+        Err(_) => {}
+    };
+    let commit_channel =
+        move |value: String, channel: fn(&mut Color) -> &mut f32, report_invalid: bool| {
+            match parse_decimal(&value, decimal_separator.get_untracked()) {
+                Some(value) => {
+                    let mut color = color.get_untracked();
+                    *channel(&mut color) = channel_from_255(value);
+                    set_valid(true);
+                    emit.run((color, ChangeSource::RgbInput));
+                }
+                None if report_invalid => set_valid(false),
+                None => {}
+            }
+        };
+    let commit_hue = move |text: String, report_invalid: bool| {
+        let Some(hue) = parse_decimal(&text, decimal_separator.get_untracked()) else {
+            if report_invalid {
+                set_valid(false);
+            }
+            return;
+        };
+        let hsla = color.with_untracked(|c| c.to_hsla());
+        set_valid(true);
+        emit.run((
+            Color::from_hsla(hue.rem_euclid(360.0), hsla[1], hsla[2], hsla[3]),
+            ChangeSource::HueInput,
         ));
-        set_hue_pointer.set(format!("{}%", (hsla[0] * 100.0 / 360.0).round()));
-        set_alpha_pointer.set(format!("{}%", (alpha as f32 / 255.0 * 100.0).round()));
-        set_saturation_pointer_top.set(format!("calc({}% - 6px)", -(hsva[2] * 100.0) + 100.0));
-        set_saturation_pointer_left.set(format!("calc({}% - 6px)", (hsva[1] * 100.0).round()));
+    };
+    let (hex_typed, hex_committed) = use_typing_commit(
+        commit_delay_ms,
+        Callback::new(move |value| commit_hex(value, false)),
+    );
+    let (hue_typed, hue_committed) = use_typing_commit(
+        commit_delay_ms,
+        Callback::new(move |value| commit_hue(value, false)),
+    );
+    let typing_channel = move |channel: fn(&mut Color) -> &mut f32| {
+        use_typing_commit(
+            commit_delay_ms,
+            Callback::new(move |value| commit_channel(value, channel, false)),
+        )
+    };
+    let (red_typed, red_committed) = typing_channel(|color| &mut color.r);
+    let (green_typed, green_committed) = typing_channel(|color| &mut color.g);
+    let (blue_typed, blue_committed) = typing_channel(|color| &mut color.b);
+    // The hex input shows the value with or without its `#`; both forms parse either way.
+    let hex_text = move |hex: String| {
+        if hex_with_hash.get() {
+            hex
+        } else {
+            hex.replace('#', "")
+        }
+    };
+    let (copied, set_copied) = signal(false);
+    // Copies `text` and raises `set_flag` for the duration of the "Copied" feedback.
+    let copy_with_feedback = move |text: String, set_flag: WriteSignal<bool>| {
+        copy_to_clipboard(&text);
+        set_flag.set(true);
+        set_timeout(
+            move || set_flag.set(false),
+            std::time::Duration::from_millis(1200),
+        );
+    };
+    let copy_preview = move || {
+        if !preview_clickable.get_untracked() {
+            return;
+        }
+        copy_with_feedback(
+            color.with_untracked(|c| format_color(c, format.get_untracked())),
+            set_copied,
+        );
+        report_interaction(InteractionEvent::Copy);
+    };
+    let (css_copied, set_css_copied) = signal(false);
+    let copy_css = move || {
+        let value = color.with_untracked(|c| format_color(c, format.get_untracked()));
+        copy_with_feedback(format!("color: {value};"), set_css_copied);
+        report_interaction(InteractionEvent::CssExport);
+    };
+    let preview_label = move || {
+        if copied.get() {
+            copied_label.get()
+        } else {
+            color.with(|c| format_color(c, format.get()))
+        }
+    };
+
+    let suggested_text_color = Memo::new(move |_| {
+        text_suggestion_ratio
+            .get()
+            .map(|ratio| color.with(|c| accessible_pair(c, ratio)))
+    });
+    let large_value_text_color = move || {
+        suggested_text_color
+            .get()
+            .unwrap_or_else(|| color.with(readable_text_color))
+            .to_hex_string()
+    };
+
+    // Debounced screen reader announcement of the current color.
+    let (announcement, set_announcement) = signal(String::new());
+    let announce_timeout = StoredValue::new(None::<TimeoutHandle>);
+    Effect::new(move |first_run: Option<()>| {
+        let text = color.with(|c| {
+            format!(
+                "Hue {} degrees, {}",
+                c.to_hsla()[0].round(),
+                format_color(c, format.get())
+            )
+        });
+        if first_run.is_none() || !announce_changes.get() {
+            return;
+        }
+        if let Some(handle) = announce_timeout.get_value() {
+            handle.clear();
+        }
+        let handle = set_timeout_with_handle(
+            move || set_announcement.set(text),
+            std::time::Duration::from_millis(500),
+        )
+        .ok();
+        announce_timeout.set_value(handle);
+    });
+    on_cleanup(move || {
+        if let Some(handle) = announce_timeout.try_get_value().flatten() {
+            handle.clear();
+        }
+    });
+
+    let decimal_input_type = move || {
+        if decimal_separator.get() == '.' {
+            "number"
+        } else {
+            "text"
+        }
+    };
+    let localized = move |value: ReadSignal<String>| {
+        move || format_decimal(&value.get(), decimal_separator.get())
+    };
+
+    // With `max_height`, scrolls a focused control out from under the pinned controls, or up
+    // from below the visible part of the container.
+    let reveal_focused = move |ev: leptos::ev::FocusEvent| {
+        if max_height.with_untracked(Option::is_none) {
+            return;
+        }
+        let (Some(container), Some(target)) = (
+            el.get_untracked(),
+            ev.target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok()),
+        ) else {
+            return;
+        };
+        let pinned = container
+            .query_selector(".leptos-color-controls")
+            .ok()
+            .flatten();
+        if pinned
+            .as_ref()
+            .is_some_and(|pinned| pinned.contains(Some(&*target)))
+        {
+            return;
+        }
+        let bounds = container.get_bounding_client_rect();
+        let visible_top =
+            bounds.top() + pinned.map_or(0.0, |pinned| pinned.get_bounding_client_rect().height());
+        let rect = target.get_bounding_client_rect();
+        if rect.top() < visible_top {
+            container
+                .set_scroll_top(container.scroll_top() - (visible_top - rect.top()).ceil() as i32);
+        } else if rect.bottom() > bounds.bottom() {
+            container.set_scroll_top(
+                container.scroll_top() + (rect.bottom() - bounds.bottom()).ceil() as i32,
+            );
+        }
+    };
+
+    let hue_preview_sv = Signal::derive(move || {
+        hue_preview_at_sv.get().then(|| {
+            let hsva = color.with(|c| c.to_hsva());
+            (hsva[1] as f64, hsva[2] as f64)
+        })
+    });
+
+    // Moves the current color onto `to` and emits the most recent color of `from`.
+    let step_history =
+        move |from: RwSignal<Vec<Color>>, to: RwSignal<Vec<Color>>, event: InteractionEvent| {
+            let Some(target) = from.try_update(Vec::pop).flatten() else {
+                return;
+            };
+            to.update(|stack| stack.push(color.get_untracked()));
+            last_recorded.set_value(None);
+            report_interaction(event);
+            emit.run((target, ChangeSource::History));
+        };
+    let undo = move || step_history(undo_stack, redo_stack, InteractionEvent::Undo);
+    let redo = move || step_history(redo_stack, undo_stack, InteractionEvent::Redo);
+    let on_history_keydown = move |ev: KeyboardEvent| {
+        // The undo history is not offered when only the swatches are shown.
+        if !show_undo.get_untracked()
+            || layout.get_untracked() == PickerLayout::SwatchesOnly
+            || !(ev.ctrl_key() || ev.meta_key())
+        {
+            return;
+        }
+        // Text fields keep their native undo.
+        let in_text_field = ev
+            .target()
+            .is_some_and(|target| target.dyn_into::<HtmlInputElement>().is_ok());
+        if in_text_field {
+            return;
+        }
+        match ev.key().to_lowercase().as_str() {
+            "z" if ev.shift_key() => redo(),
+            "z" => undo(),
+            "y" => redo(),
+            _ => return,
+        }
+        ev.prevent_default();
+    };
+
+    // The alpha sidebar sits next to the saturation area, so it needs the area to be shown.
+    let sidebar_alpha = move || layout.get() == PickerLayout::SidebarAlpha && !grayscale_only.get();
+    let lightness_value = Signal::derive(move || color.with(|c| f64::from(c.to_hsla()[2])));
+    let row_layout = move || layout.get() == PickerLayout::Row;
+    let swatches_only = move || layout.get() == PickerLayout::SwatchesOnly;
+    let inputs_side =
+        move || inputs_position.get() == InputsPosition::Side && !row_layout() && !swatches_only();
+    // `var()` references need the computed style, so they are resolved once the picker is mounted.
+    let resolved_swatches = RwSignal::new(Vec::<Color>::new());
+    Effect::new(move |_| {
+        let values = css_swatches.get();
+        if let Some(container) = el.get() {
+            resolved_swatches.set(
+                values
+                    .iter()
+                    .filter_map(|value| resolve_css_color(&container, value))
+                    .collect(),
+            );
+        }
+    });
+    Effect::new(move |resolved: Option<bool>| {
+        if resolved == Some(true) {
+            return true;
+        }
+        let Some(container) = el.get() else {
+            return false;
+        };
+        let initial = initial_css_color
+            .get_untracked()
+            .and_then(|value| resolve_css_color(&container, &value));
+        if let Some(initial) = initial {
+            emit.run((initial, ChangeSource::Programmatic));
+        }
+        true
     });
+    let swatches = Signal::derive(move || {
+        let mut all = swatches.get();
+        all.extend(resolved_swatches.get());
+        all
+    });
+    let selected_swatch = Signal::derive(move || Some(color.get()));
+    let select_swatch =
+        Callback::new(move |swatch: Color| emit.run((swatch, ChangeSource::Swatch)));
+    let hue_value = Signal::derive(move || f64::from(color.with(|c| c.to_hsla()[0])));
+    let alpha_value = Signal::derive(move || color.with(|c| f64::from(c.a)));
+    let on_alpha_change = move |left: f64, _top: f64| {
+        let mut color = color.get_untracked();
+        color.a = quantize_alpha(left);
+        emit.run((color, ChangeSource::Alpha));
+    };
 
     view! {
-        <div node_ref={el} class="leptos-color-container" style=move || theme.with(|value| value.to_style())>
-            <Saturation on_change=move |left: f64,top: f64| {
-                let mut hsva = color.get().to_hsva();
-                hsva[2] = (1.0 - top) as f32;
-                hsva[1] = left as f32;
-                if hsva[2] <= 0.0 {
-                    hsva[2] = 0.001;
-                }
-                if hsva[1] <= 0.0 {
-                    hsva[1] = 0.001;
+        <div
+            node_ref={el}
+            class="leptos-color-container"
+            class:leptos-color-row=row_layout
+            class:leptos-color-inputs-side=inputs_side
+            class:leptos-color-swatches-only=swatches_only
+            class:leptos-color-scrollable=move || max_height.with(Option::is_some)
+            style:max-height=move || max_height.get()
+            // Rendered once, so server-rendered HTML shows the theme and the handles in place;
+            // updates go through the effects above so they keep each other's variables.
+            style=format!(
+                "{} {}",
+                theme.with_untracked(|value| value.to_style()),
+                untrack(color_variables).to_style()
+            )
+            on:keydown=on_history_keydown
+            // Every press starts a new gesture, so separate drags are undone separately.
+            on:mousedown=move |_| {
+                last_recorded.set_value(None);
+                last_interaction.set_value(None);
+            }
+            on:touchstart=move |_| {
+                last_recorded.set_value(None);
+                last_interaction.set_value(None);
+            }
+            on:focusin=move |ev| {
+                last_interaction.set_value(None);
+                reveal_focused(ev);
+            }
+        >
+            <Show when=move || !swatches_only()>
+            <div class="leptos-color-controls">
+            <Show when=move || !row_layout() && !grayscale_only.get()>
+            <div class:leptos-color-sidebar=sidebar_alpha>
+            <Saturation locked=lock_saturation model=internal_model snap_resolution=snap_resolution bordered=saturation_border touch_offset=touch_offset on_change=move |left: f64,top: f64| {
+                let epsilon = value_epsilon.get_untracked().clamp(0.0, 0.5);
+                match internal_model.get_untracked() {
+                    ColorModel::Hsv => {
+                        let mut hsva = color.with_untracked(|c| c.to_hsva());
+                        hsva[2] = ((1.0 - top) as f32).max(epsilon);
+                        hsva[1] = (left as f32).max(epsilon);
+                        emit.run((Color::from_hsva(hsva[0], hsva[1], hsva[2], hsva[3]), ChangeSource::Saturation));
+                    }
+                    ColorModel::Hsl => {
+                        // Keep away from the edges where the hue becomes undefined
+                        let mut hsla = color.with_untracked(|c| c.to_hsla());
+                        hsla[2] = ((1.0 - top) as f32).clamp(epsilon, 1.0 - epsilon);
+                        hsla[1] = (left as f32).max(epsilon);
+                        emit.run((Color::from_hsla(hsla[0], hsla[1], hsla[2], hsla[3]), ChangeSource::Saturation));
+                    }
                 }
-                on_change.run(Color::from_hsva(hsva[0], hsva[1], hsva[2], hsva[3]));
             }/>
+            <Show
+                when=move || { !hide_alpha.get() && sidebar_alpha() }
+            >
+                <Alpha locked=lock_alpha value=alpha_value checkerboard=alpha_checkerboard orientation=Orientation::Vertical on_change=on_alpha_change/>
+            </Show>
+            </div>
+            </Show>
             <div class="leptos-color-flex">
-                <div class="leptos-color-value-wrapper">
-                    <div class="leptos-color-checkboard">
-                        <div class="leptos-color-value" />
+                <div
+                    class="leptos-color-value-wrapper"
+                    class:leptos-color-value-clickable=move || preview_clickable.get()
+                    class:leptos-color-copied=move || copied.get()
+                    title=preview_label
+                    role=move || preview_clickable.get().then_some("button")
+                    aria-label=move || preview_clickable.get().then(|| format!("{}: {}", copy_label.get(), preview_label()))
+                    tabindex=move || preview_clickable.get().then_some("0")
+                    on:click=move |_| copy_preview()
+                    on:dragover=move |ev| {
+                        // Allow dropping by cancelling the default.
+                        ev.prevent_default();
+                        if let Some(data) = ev.data_transfer() {
+                            data.set_drop_effect("copy");
+                        }
+                    }
+                    on:drop=move |ev| {
+                        ev.prevent_default();
+                        let dropped = ev
+                            .data_transfer()
+                            .and_then(|data| data.get_data("text/plain").ok())
+                            .and_then(|text| parse_color(&text));
+                        if let Some(dropped) = dropped {
+                            emit.run((dropped, ChangeSource::Drop));
+                        }
+                    }
+                    on:keydown=move |ev| {
+                        if preview_clickable.get_untracked() && (ev.key() == "Enter" || ev.key() == " ") {
+                            ev.prevent_default();
+                            copy_preview();
+                        }
+                    }
+                >
+                    <div
+                        class="leptos-color-checkboard"
+                        class:leptos-color-checkboard-plain=move || !show_preview_checkerboard()
+                    >
+                        <div class="leptos-color-value" style=preview_style />
                     </div>
                 </div>
                 <div class="leptos-color-ranges">
-                    <Hue on_change=move |left,_| {
-                        let hsla = color.get().to_hsla();
-                        on_change.run(Color::from_hsla((left*360.0) as f32, hsla[1], hsla[2], hsla[3]));
-                    } />
                     <Show
-                        when=move || { !hide_alpha.get()}
+                        when=move || grayscale_only.get()
+                        fallback=move || view! {
+                            <Hue hue_min=hue_min hue_max=hue_max locked=lock_hue value=hue_value preview_sv=hue_preview_sv on_change=move |left,_| {
+                                let hsla = color.with_untracked(|c| c.to_hsla());
+                                emit.run((Color::from_hsla((left*360.0) as f32, hsla[1], hsla[2], hsla[3]), ChangeSource::Hue));
+                            } />
+                        }
+                    >
+                        <Slider
+                            class="leptos-color-lightness"
+                            value=lightness_value
+                            label="Lightness"
+                            style="background: linear-gradient(to right, #000, #fff);".to_string()
+                            on_change=move |(along, _): (f64, f64)| {
+                                let lightness = along as f32;
+                                let alpha = color.with_untracked(|c| c.a);
+                                emit.run((Color::new(lightness, lightness, lightness, alpha), ChangeSource::Lightness));
+                            }
+                        />
+                    </Show>
+                    <Show when=move || show_hue_label.get() && !grayscale_only.get()>
+                        <span class="leptos-color-hue-label">
+                            {move || format!("{}°", hue_value.get().round() as u16 % 360)}
+                        </span>
+                    </Show>
+                    <Show
+                        when=move || { !hide_alpha.get() && !sidebar_alpha() }
                       >
-                      <Alpha on_change=move |left,_| {
-                          let mut color = color.get();
-                          color.a = left as f32;
-                          on_change.run(color);
-                      }/>
+                      <Alpha locked=lock_alpha value=alpha_value checkerboard=alpha_checkerboard on_change=on_alpha_change/>
+                    </Show>
+                    <Show
+                        when=move || { show_alpha_label.get() && !hide_alpha.get() }
+                    >
+                        <span class="leptos-color-alpha-label">
+                            {move || format!("{}%", (color.with(|c| c.a) * 100.0).round())}
+                        </span>
                     </Show>
                 </div>
             </div>
 
+            <Show when=move || large_value.get()>
+                <div
+                    class="leptos-color-large-value"
+                    style:background=move || color.with(|c| c.to_hex_string())
+                    style:color=large_value_text_color
+                >
+                    {move || color.with(|c| format_color(c, format.get()))}
+                </div>
+            </Show>
+            {move || {
+                suggested_text_color
+                    .get()
+                    .map(|text| {
+                        let hex = text.to_hex_string();
+                        view! {
+                            <div class="leptos-color-text-suggestion">
+                                <span
+                                    class="leptos-color-text-suggestion-sample"
+                                    style:background=move || color.with(|c| c.to_hex_string())
+                                    style:color=hex.clone()
+                                >
+                                    "Aa"
+                                </span>
+                                <span>{hex}</span>
+                            </div>
+                        }
+                    })
+            }}
+            </div>
+            </Show>
+
+            <div class="leptos-color-visually-hidden" aria-live="polite" aria-atomic="true">
+                {move || announce_changes.get().then(|| announcement.get())}
+            </div>
+
+            <Show when=move || !swatches_only()>
             <div class="leptos-color-inputs">
                 <Show
                     when=move || { !hide_hex.get()}
                 >
-                <label class="leptos-color-label">
-                    <div class="leptos-color-wrapper">
-                        <span class="leptos-color-prefix">"#"</span>
+                <label class="leptos-color-label leptos-color-hex-label">
+                    <div class="leptos-color-wrapper" class:leptos-color-invalid=move || hex_invalid.get()>
+                        <Show when=move || !hex_with_hash.get()>
+                            <span class="leptos-color-prefix">"#"</span>
+                        </Show>
 
                         <input
                         class="leptos-color-input"
                         type="text"
                         name="hex"
+                        node_ref=hex_ref
                         style:width="54px"
-                        on:blur={move |ev| {
-                            match event_target_value(&ev).parse::<Color>() {
-                                Ok(new_color) => on_change.run(new_color),
-                                Err(_) => {},
-                            }
-                        }}
-                        on:change={move |ev| {
-                            match event_target_value(&ev).parse::<Color>() {
-                                Ok(new_color) => on_change.run(new_color),
-                                Err(_) => {},
-                            }
-                        }}
-                        prop:value={move || hex.get().replace("#", "")}
-                        maxlength={6}
+                        on:input=move |ev| hex_typed.run(event_target_value(&ev))
+                        on:blur=move |ev| {
+                            hex_committed.run(());
+                            commit_hex(event_target_value(&ev), true);
+                        }
+                        on:change=move |ev| {
+                            hex_committed.run(());
+                            commit_hex(event_target_value(&ev), true);
+                        }
+                        on:keydown=move |ev| handle_input_keydown(&ev, || {
+                            hex_invalid.set(false);
+                            set_valid(true);
+                            hex_text(hex.get_untracked())
+                        })
+                        prop:value={move || hex_text(hex.get())}
+                        maxlength=move || if hex_with_hash.get() { 7 } else { 6 }
                         />
                         </div>
                         <span>"Hex"</span>
@@ -259,28 +1511,30 @@ pub fn ColorPicker(
                 <Show
                     when=move || { !hide_rgb.get()}
                 >
+                <fieldset class="leptos-color-fieldset">
+                <legend class="leptos-color-visually-hidden">"RGB"</legend>
                 <label class="leptos-color-label">
                     <div class="leptos-color-wrapper">
                         <input
                             class="leptos-color-input"
-                            prop:value=red
+                            prop:value=localized(red)
+                            on:keydown=move |ev| handle_input_keydown(&ev, || format_decimal(&red.get_untracked(), decimal_separator.get_untracked()))
                             name="red"
-                            type="number"
+                            type=decimal_input_type
+                            inputmode="decimal"
+                            class:leptos-color-scrubbable=move || scrubbable_inputs.get()
+                            on:mousedown=move |ev| scrub_red.run(ev.into())
+                            on:touchstart=move |ev| scrub_red.run(ev.into())
                             style:width="42px"
                             min={0}
                             max={255}
-                            step={1}
+                            step=move || if round_display.get() { "1" } else { "any" }
                             autocomplete="off"
-                            on:change={move |ev| {
-                                match event_target_value(&ev).parse::<u8>() {
-                                    Ok(value) => {
-                                        let mut color = color.get();
-                                        color.r = value as f32 / 255.0;
-                                        on_change.run(color);
-                                    },
-                                    Err(_) => todo!(),
-                                }
-                            }}
+                            on:input=move |ev| red_typed.run(event_target_value(&ev))
+                            on:change=move |ev| {
+                                red_committed.run(());
+                                commit_channel(event_target_value(&ev), |color| &mut color.r, true);
+                            }
                         />
 
                             </div>
@@ -290,24 +1544,24 @@ pub fn ColorPicker(
                     <div class="leptos-color-wrapper">
                         <input
                             class="leptos-color-input"
-                            prop:value=green
+                            prop:value=localized(green)
+                            on:keydown=move |ev| handle_input_keydown(&ev, || format_decimal(&green.get_untracked(), decimal_separator.get_untracked()))
                             name="green"
-                            type="number"
+                            type=decimal_input_type
+                            inputmode="decimal"
+                            class:leptos-color-scrubbable=move || scrubbable_inputs.get()
+                            on:mousedown=move |ev| scrub_green.run(ev.into())
+                            on:touchstart=move |ev| scrub_green.run(ev.into())
                             style:width="42px"
                             min={0}
                             max={255}
-                            step={1}
+                            step=move || if round_display.get() { "1" } else { "any" }
                             autocomplete="off"
-                            on:change={move |ev| {
-                                match event_target_value(&ev).parse::<u8>() {
-                                    Ok(value) => {
-                                        let mut color = color.get();
-                                        color.g = value as f32 / 255.0;
-                                        on_change.run(color);
-                                    },
-                                    Err(_) => todo!(),
-                                }
-                            }}
+                            on:input=move |ev| green_typed.run(event_target_value(&ev))
+                            on:change=move |ev| {
+                                green_committed.run(());
+                                commit_channel(event_target_value(&ev), |color| &mut color.g, true);
+                            }
                         />
                     </div>
                     <span>"G"</span>
@@ -316,28 +1570,61 @@ pub fn ColorPicker(
                     <div class="leptos-color-wrapper">
                         <input
                             class="leptos-color-input"
-                            prop:value=blue
+                            prop:value=localized(blue)
+                            on:keydown=move |ev| handle_input_keydown(&ev, || format_decimal(&blue.get_untracked(), decimal_separator.get_untracked()))
                             name="blue"
-                            type="number"
+                            type=decimal_input_type
+                            inputmode="decimal"
+                            class:leptos-color-scrubbable=move || scrubbable_inputs.get()
+                            on:mousedown=move |ev| scrub_blue.run(ev.into())
+                            on:touchstart=move |ev| scrub_blue.run(ev.into())
                             style:width="42px"
                             min={0}
                             max={255}
-                            step={1}
+                            step=move || if round_display.get() { "1" } else { "any" }
                             autocomplete="off"
-                            on:change={move |ev| {
-                                match event_target_value(&ev).parse::<u8>() {
-                                    Ok(value) => {
-                                        let mut color = color.get();
-                                        color.b = value as f32 / 255.0;
-                                        on_change.run(color);
-                                    },
-                                    Err(_) => {},
-                                }
-                            }}
+                            on:input=move |ev| blue_typed.run(event_target_value(&ev))
+                            on:change=move |ev| {
+                                blue_committed.run(());
+                                commit_channel(event_target_value(&ev), |color| &mut color.b, true);
+                            }
                         />
                     </div>
                     <span>"B"</span>
                 </label>
+                </fieldset>
+                </Show>
+                <Show
+                    when=move || { !hide_lab.get()}
+                >
+                    {lab_inputs()}
+                </Show>
+                <Show
+                    when=move || { show_hue_input.get() && !grayscale_only.get() }
+                >
+                <label class="leptos-color-label">
+                    <div class="leptos-color-wrapper">
+                        <input
+                            class="leptos-color-input"
+                            prop:value=localized(hue)
+                            on:keydown=move |ev| handle_input_keydown(&ev, || format_decimal(&hue.get_untracked(), decimal_separator.get_untracked()))
+                            name="hue"
+                            type=decimal_input_type
+                            inputmode="decimal"
+                            style:width="42px"
+                            min={0}
+                            max={360}
+                            step=move || if round_display.get() { "1" } else { "any" }
+                            autocomplete="off"
+                            on:input=move |ev| hue_typed.run(event_target_value(&ev))
+                            on:change=move |ev| {
+                                hue_committed.run(());
+                                commit_hue(event_target_value(&ev), true);
+                            }
+                        />
+                    </div>
+                    <span>"H"</span>
+                </label>
                 </Show>
                 <Show
                     when=move || { !hide_alpha.get()}
@@ -347,8 +1634,12 @@ pub fn ColorPicker(
                     <input
                         class="leptos-color-input"
                         prop:value=alpha
+                        on:keydown=move |ev| handle_input_keydown(&ev, || alpha.get_untracked())
                         name="alpha"
                         type="number"
+                        class:leptos-color-scrubbable=move || scrubbable_inputs.get()
+                        on:mousedown=move |ev| scrub_alpha.run(ev.into())
+                        on:touchstart=move |ev| scrub_alpha.run(ev.into())
                         style:width="42px"
                         min={0}
                         max={255}
@@ -357,11 +1648,12 @@ pub fn ColorPicker(
                         on:change={move |ev| {
                             match event_target_value(&ev).parse::<u8>() {
                                 Ok(value) => {
-                                    let mut color = color.get();
-                                    color.a = value as f32 / 255.0;
-                                    on_change.run(color);
+                                    let mut color = color.get_untracked();
+                                    color.a = channel_from_255(value as f32);
+                                    set_valid(true);
+                                    emit.run((color, ChangeSource::AlphaInput));
                                 },
-                                Err(_) => {},
+                                Err(_) => set_valid(false),
                             }
                         }}/>
                     </div>
@@ -369,6 +1661,242 @@ pub fn ColorPicker(
                 </label>
                 </Show>
             </div>
+            </Show>
+            <Show when=move || !swatches.with(Vec::is_empty)>
+                <Swatches colors=swatches selected=selected_swatch on_select=select_swatch />
+            </Show>
+            <Show when=move || !recent_colors.with(Vec::is_empty)>
+                <div class="leptos-color-recent" role="group" aria-label="Recent colors">
+                    <Swatches
+                        colors=recent_colors
+                        selected=selected_swatch
+                        on_select=select_swatch
+                        scrollable=true
+                    />
+                </div>
+            </Show>
+            <Show when=move || show_named_dropdown.get() && !swatches_only()>
+                <select
+                    class="leptos-color-named"
+                    aria-label="Named color"
+                    prop:value=move || color.with(name_of).unwrap_or_default()
+                    on:change=move |ev| {
+                        if let Some(named) = named_color(&event_target_value(&ev)) {
+                            emit.run((named, ChangeSource::NamedColor));
+                        }
+                    }
+                >
+                    <option value="" disabled>"Named color"</option>
+                    {NAMED_COLORS
+                        .iter()
+                        .map(|(name, [r, g, b])| {
+                            let background = Color::from_rgba8(*r, *g, *b, 255);
+                            view! {
+                                <option
+                                    value=*name
+                                    style:background-color=background.to_hex_string()
+                                    style:color=readable_text_color(&background).to_hex_string()
+                                >
+                                    {*name}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </Show>
+            <Show when=move || (show_undo.get() || show_css_export.get()) && !swatches_only()>
+                <div class="leptos-color-actions">
+                    <Show when=move || show_undo.get()>
+                        <button
+                            type="button"
+                            class="leptos-color-action-button"
+                            aria-label="Undo"
+                            title="Undo"
+                            disabled=move || undo_stack.with(Vec::is_empty)
+                            on:click=move |_| undo()
+                        >
+                            "↶"
+                        </button>
+                        <button
+                            type="button"
+                            class="leptos-color-action-button"
+                            aria-label="Redo"
+                            title="Redo"
+                            disabled=move || redo_stack.with(Vec::is_empty)
+                            on:click=move |_| redo()
+                        >
+                            "↷"
+                        </button>
+                    </Show>
+                    <Show when=move || show_css_export.get()>
+                        <button
+                            type="button"
+                            class="leptos-color-action-button"
+                            title=move || color.with(|c| format!("color: {};", format_color(c, format.get())))
+                            on:click=move |_| copy_css()
+                        >
+                            {move || if css_copied.get() { copied_label.get() } else { css_export_label.get() }}
+                        </button>
+                    </Show>
+                </div>
+            </Show>
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantization_yields_the_levels_of_the_bit_depth() {
+        for bits in 1..=8u8 {
+            let levels = (1u32 << bits) - 1;
+            let mut seen = (0..=1000)
+                .map(|step| quantize_channel(step as f32 / 1000.0, bits))
+                .map(|value| (value * levels as f32).round() as u32)
+                .collect::<Vec<_>>();
+            seen.dedup();
+            assert_eq!(seen, (0..=levels).collect::<Vec<_>>(), "{bits} bits");
+        }
+    }
+
+    #[test]
+    fn quantization_keeps_the_extremes_and_clamps() {
+        assert_eq!(quantize_channel(0.0, 1), 0.0);
+        assert_eq!(quantize_channel(1.0, 1), 1.0);
+        assert_eq!(quantize_channel(0.49, 1), 0.0);
+        assert_eq!(quantize_channel(0.51, 1), 1.0);
+        assert_eq!(quantize_channel(1.5, 4), 1.0);
+        assert_eq!(quantize_channel(-0.5, 4), 0.0);
+        // 4-bit levels read back as doubled hex digits, e.g. 0x88.
+        assert_eq!(channel_to_u8(quantize_channel(0.5, 4)), 0x88);
+    }
+
+    #[test]
+    fn eight_bit_quantization_matches_bytes() {
+        for byte in 0..=255u8 {
+            let value = channel_from_255(byte as f32);
+            assert_eq!(channel_to_u8(quantize_channel(value, 8)), byte);
+        }
+    }
+
+    #[test]
+    fn degenerate_conversions_are_detected() {
+        assert!(!is_finite_color(&Color::from_hsva(f32::NAN, 1.0, 1.0, 1.0)));
+        assert!(!is_finite_color(&Color::new(f32::INFINITY, 0.0, 0.0, 1.0)));
+        assert!(!is_finite_color(&Color::new(0.0, 0.0, 0.0, f32::NAN)));
+        // Gray has an undefined hue, but converting it back is fine.
+        let gray = Color::from_rgba8(128, 128, 128, 255);
+        let [h, s, v, a] = gray.to_hsva();
+        assert!(is_finite_color(&Color::from_hsva(h, s, v, a)));
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod drag_tests {
+    use super::*;
+    use crate::hooks::use_position::test_support;
+    use leptos::task::Executor;
+    use wasm_bindgen_test::*;
+    use web_sys::wasm_bindgen::JsCast as _;
+    use web_sys::{HtmlElement, MouseEvent, MouseEventInit};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Mounts a `ColorPicker` starting at red and returns its parent and the last emitted color.
+    async fn mount_red_picker() -> (HtmlElement, RwSignal<Option<Color>>) {
+        test_support::reset();
+        let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+        let emitted = RwSignal::new(None::<Color>);
+        let parent = document()
+            .create_element("div")
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+        document().body().unwrap().append_child(&parent).unwrap();
+        leptos::mount::mount_to(parent.clone(), move || {
+            view! {
+                <ColorPicker
+                    color=color
+                    on_change=move |c| {
+                        color.set(c);
+                        emitted.set(Some(c));
+                    }
+                />
+            }
+        })
+        .forget();
+        Executor::tick().await;
+        (parent, emitted)
+    }
+
+    fn mouse_event(kind: &str, client_x: i32, client_y: i32) -> MouseEvent {
+        let init = MouseEventInit::new();
+        init.set_bubbles(true);
+        init.set_client_x(client_x);
+        init.set_client_y(client_y);
+        MouseEvent::new_with_mouse_event_init_dict(kind, &init).unwrap()
+    }
+
+    fn press(parent: &HtmlElement, selector: &str, client_x: i32, client_y: i32) {
+        parent
+            .query_selector(selector)
+            .unwrap()
+            .unwrap_or_else(|| panic!("no element matches {selector}"))
+            .dispatch_event(&mouse_event("mousedown", client_x, client_y))
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn saturation_maps_a_synthetic_rect_to_hsv() {
+        let (parent, emitted) = mount_red_picker().await;
+        test_support::set_rect(Some((0.0, 0.0, 255.0, 255.0)));
+
+        // A fifth of the way across the top edge: saturation 0.2 at full value.
+        press(&parent, ".leptos-color-color", 51, 0);
+
+        let emitted = emitted.get_untracked().expect("on_change was not called");
+        assert_eq!(emitted.to_rgba8(), [255, 204, 204, 255]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn hue_drag_follows_the_pointer_across_a_synthetic_rect() {
+        let (parent, emitted) = mount_red_picker().await;
+        test_support::set_rect(Some((0.0, 0.0, 360.0, 10.0)));
+
+        press(&parent, ".leptos-color-hue-container", 0, 5);
+        Executor::tick().await;
+        document()
+            .dispatch_event(&mouse_event("mousemove", 120, 5))
+            .unwrap();
+        document()
+            .dispatch_event(&mouse_event("mouseup", 240, 5))
+            .unwrap();
+
+        // The release position is reported too, so the drag ends on blue, not green.
+        let emitted = emitted.get_untracked().expect("on_change was not called");
+        assert_eq!(emitted.to_rgba8(), [0, 0, 255, 255]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn alpha_maps_a_synthetic_rect_to_opacity() {
+        let (parent, emitted) = mount_red_picker().await;
+        test_support::set_rect(Some((0.0, 0.0, 255.0, 10.0)));
+
+        press(&parent, ".leptos-color-alpha-container", 51, 5);
+
+        let emitted = emitted.get_untracked().expect("on_change was not called");
+        assert_eq!(emitted.to_rgba8(), [255, 0, 0, 51]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn moves_fed_to_the_hue_hook_emit_hues() {
+        let (_, emitted) = mount_red_picker().await;
+
+        // The saturation area registers its hook first, then the hue slider.
+        test_support::move_to(1, (0.5, 0.0));
+
+        let emitted = emitted.get_untracked().expect("on_change was not called");
+        assert_eq!(emitted.to_rgba8(), [0, 255, 255, 255]);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    components::{color_picker::ColorPicker, popover::PickerPopover},
+    mount_style::mount_style,
+    theme::{use_theme, Theme},
+};
+use csscolorparser::Color;
+use leptos::prelude::*;
+use leptos_node_ref::AnyNodeRef;
+
+/// The outline of a [`ColorSwatchButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SwatchShape {
+    /// A square with the theme's border radius.
+    #[default]
+    Square,
+    /// A circle.
+    Circle,
+}
+
+/// A colored swatch button that opens a color picker popover when clicked.
+///
+/// This is the compact counterpart of `ColorInput`: instead of a text field, the trigger is a
+/// small chip filled with the current color. It uses the same floating popover as `ColorInput`.
+///
+/// # Props
+///
+/// * `theme`: An optional `Signal<Theme>` representing the theme for the component. Falls back to the
+///   theme of the nearest `ThemeProvider`, then to `Theme::default()`.
+/// * `color`: A `Signal<Color>` representing the current color value.
+/// * `hide_alpha`: An optional `Signal<bool>` to hide the alpha channel in the color picker.
+/// * `hide_hex`: An optional `Signal<bool>` to hide the hexadecimal color input in the color picker.
+/// * `hide_rgb`: An optional `Signal<bool>` to hide the RGB color input in the color picker.
+/// * `force_opaque`: An optional `Signal<bool>` that forces the alpha of every emitted color to `1.0`.
+/// * `size`: The width and height of the swatch as a CSS length. Defaults to `"24px"`.
+/// * `shape`: An optional `Signal<SwatchShape>` controlling the outline. Defaults to `SwatchShape::Square`.
+/// * `on_change`: A `Callback<Color>` that is called when the color value changes.
+///
+/// # Behavior
+///
+/// - Clicking the swatch toggles the color picker popover.
+/// - The popover closes when clicking outside of it or clicking the swatch again.
+/// - Translucent colors are shown over a checkerboard.
+/// - The color picker is only mounted while the popover is open.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_color::{components::color_swatch_button::{ColorSwatchButton, SwatchShape}, Color};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+///
+///     view! {
+///         <ColorSwatchButton
+///             color=color
+///             shape=SwatchShape::Circle
+///             on_change=move |new_color| color.set(new_color)
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn ColorSwatchButton(
+    #[prop(into, optional)] theme: Option<Signal<Theme>>,
+    #[prop(into)] color: Signal<Color>,
+    #[prop(into, optional)] hide_alpha: Signal<bool>,
+    #[prop(into, optional)] hide_hex: Signal<bool>,
+    #[prop(into, optional)] hide_rgb: Signal<bool>,
+    #[prop(into, optional)] force_opaque: Signal<bool>,
+    #[prop(into, default = "24px".to_string().into())] size: Signal<String>,
+    #[prop(into, optional)] shape: Signal<SwatchShape>,
+    #[prop(into)] on_change: Callback<Color>,
+) -> impl IntoView {
+    mount_style(
+        "ColorSwatchButton",
+        include_str!("./color_swatch_button.css"),
+    );
+    let theme = use_theme(theme);
+    let reference_ref = AnyNodeRef::new();
+    let (open, set_open) = signal(false);
+
+    view! {
+        <div
+            class="color-input-container"
+            style=move || {
+                format!(
+                    "position: relative; display: inline-block; {}",
+                    theme.with(|value| value.to_style()),
+                )
+            }
+        >
+            <button
+                type="button"
+                class="leptos-color-swatch-button"
+                class:leptos-color-swatch-button-circle=move || shape.get() == SwatchShape::Circle
+                style:width=move || size.get()
+                style:height=move || size.get()
+                aria-label="Choose color"
+                aria-haspopup="dialog"
+                aria-expanded=move || open.get().to_string()
+                node_ref=reference_ref
+                on:click=move |_| set_open.update(|open| *open = !*open)
+            >
+                <span
+                    class="leptos-color-swatch-button-color"
+                    style:background=move || color.with(|c| c.to_hex_string())
+                />
+            </button>
+            <PickerPopover reference_ref=reference_ref open=open set_open=set_open>
+                // Only mounted while open, so closed popovers run no picker effects.
+                <Show when=move || open.get()>
+                    <ColorPicker
+                        theme=theme
+                        color=color
+                        hide_hex=hide_hex
+                        hide_rgb=hide_rgb
+                        hide_alpha=hide_alpha
+                        force_opaque=force_opaque
+                        on_change=on_change
+                    />
+                </Show>
+            </PickerPopover>
+        </div>
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    components::color_picker::{ColorPicker, PickerLayout},
+    theme::{use_theme, Theme},
+};
+use csscolorparser::Color;
+use leptos::prelude::*;
+
+/// A [`ColorPicker`] controlled through separate hue, saturation/value and alpha signals.
+///
+/// This suits apps that store the HSV components of a color independently instead of a single
+/// [`Color`]. The picker renders the color composed from the three signals and writes changes
+/// back to each of them. For everything else, prefer `ColorPicker` with its `color` prop.
+///
+/// # Props
+///
+/// * `hue`: An `RwSignal<f64>` holding the hue in degrees (`0..360`).
+/// * `sv`: An `RwSignal<(f64, f64)>` holding saturation and value, both in `0..=1`.
+/// * `alpha`: An `RwSignal<f64>` holding the alpha in `0..=1`.
+/// * `theme`, `hide_alpha`, `hide_hex`, `hide_rgb`, `layout`: Passed through to the `ColorPicker`.
+///
+/// # Behavior
+///
+/// - Only signals whose value actually changed are written.
+/// - The hue is kept when the color becomes gray (saturation or value of `0`), where it is not
+///   recoverable from the color itself. Likewise, the saturation is kept when the value is `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_color::components::hsv_color_picker::HsvColorPicker;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let hue = RwSignal::new(210.0);
+///     let sv = RwSignal::new((0.8, 0.9));
+///     let alpha = RwSignal::new(1.0);
+///
+///     view! { <HsvColorPicker hue=hue sv=sv alpha=alpha /> }
+/// }
+/// ```
+#[component]
+pub fn HsvColorPicker(
+    hue: RwSignal<f64>,
+    sv: RwSignal<(f64, f64)>,
+    alpha: RwSignal<f64>,
+    #[prop(into, optional)] theme: Option<Signal<Theme>>,
+    #[prop(into, optional)] hide_alpha: Signal<bool>,
+    #[prop(into, optional)] hide_hex: Signal<bool>,
+    #[prop(into, optional)] hide_rgb: Signal<bool>,
+    #[prop(into, optional)] layout: Signal<PickerLayout>,
+) -> impl IntoView {
+    let theme = use_theme(theme);
+    let color = Signal::derive(move || {
+        let (saturation, value) = sv.get();
+        Color::from_hsva(
+            hue.get() as f32,
+            saturation as f32,
+            value as f32,
+            alpha.get() as f32,
+        )
+    });
+
+    let on_change = move |color: Color| {
+        let [new_hue, saturation, value, new_alpha] = color.to_hsva().map(f64::from);
+        if saturation > 0.0 && value > 0.0 && hue.get_untracked() != new_hue {
+            hue.set(new_hue);
+        }
+        let (old_saturation, old_value) = sv.get_untracked();
+        let saturation = if value > 0.0 {
+            saturation
+        } else {
+            old_saturation
+        };
+        if (old_saturation, old_value) != (saturation, value) {
+            sv.set((saturation, value));
+        }
+        if alpha.get_untracked() != new_alpha {
+            alpha.set(new_alpha);
+        }
+    };
+
+    view! {
+        <ColorPicker
+            theme=theme
+            color=color
+            hide_alpha=hide_alpha
+            hide_hex=hide_hex
+            hide_rgb=hide_rgb
+            layout=layout
+            on_change=on_change
+        />
+    }
+}
@@ -2,10 +2,7 @@ use csscolorparser::Color;
 use leptos::logging::warn;
 use leptos::prelude::*;
 
-use crate::{
-    hooks::use_position::{use_position, UsePositionProps},
-    mount_style::mount_style,
-};
+use crate::{components::slider::Slider, mount_style::mount_style};
 /// A component for selecting the hue of a color.
 ///
 /// This component provides a horizontal slider that allows users to select
@@ -17,12 +14,24 @@ use crate::{
 ///   The callback receives a tuple of (left, top) values, where:
 ///   - `left` represents the hue value (0 to 1, mapping to 0° to 360° in the color wheel)
 ///   - `top` is not used for this component but is included for consistency with other components
+/// * `hue_min`: The hue in degrees at the left end of the slider. Defaults to `0`.
+/// * `hue_max`: The hue in degrees at the right end of the slider. Defaults to `360`.
+///   If `hue_max` is smaller than `hue_min` the range wraps around, e.g. `330` to `30`
+///   selects the reds on both sides of 0°.
+/// * `locked`: An optional `Signal<bool>` that keeps the control visible but ignores pointer input
+///   and dims it via the `leptos-color-locked` class.
+/// * `value`: An optional `Signal<f64>` with the current hue in degrees. It makes the slider focusable
+///   and operable with the keyboard, and is announced in degrees.
+/// * `preview_sv`: An optional `Signal<Option<(f64, f64)>>` with an HSV saturation and value. When set,
+///   the gradient shows every hue at that saturation and value instead of the fully saturated rainbow.
 ///
 /// # Behavior
 ///
 /// - The component renders a horizontal bar with a gradient representing the full color spectrum.
 /// - Users can click, tap, or drag along this bar to select a hue value.
-/// - The component uses the `use_position` hook to handle mouse and touch interactions.
+/// - When a hue range is set, the slider only covers that range and the gradient is clipped to it.
+/// - The component is built on the shared slider primitive, which uses the `use_position` hook to
+///   handle mouse and touch interactions.
 /// - As the user interacts with the component, the `on_change` callback is triggered with
 ///   the new position values.
 ///
@@ -53,21 +62,99 @@ use crate::{
 ///
 /// This example creates a `Hue` component and displays the selected hue value in degrees.
 #[component]
-pub fn Hue(#[prop(into)] on_change: Callback<(f64, f64)>) -> impl IntoView {
+pub fn Hue(
+    #[prop(into)] on_change: Callback<(f64, f64)>,
+    #[prop(into, default = 0.0.into())] hue_min: Signal<f64>,
+    #[prop(into, default = 360.0.into())] hue_max: Signal<f64>,
+    #[prop(into, optional)] locked: Signal<bool>,
+    #[prop(into, optional)] preview_sv: Signal<Option<(f64, f64)>>,
+    #[prop(optional)] value: Option<Signal<f64>>,
+) -> impl IntoView {
     mount_style("Hue", include_str!("./hue.css"));
-    let handle_move = Callback::new(move |(left, top): (f64, f64)| on_change.run((left, top)));
+    let handle_move = Callback::new(move |(left, top): (f64, f64)| {
+        let hue = hue_from_position(left, hue_min.get_untracked(), hue_max.get_untracked());
+        on_change.run((hue / 360.0, top))
+    });
+    let gradient = move || {
+        let (min, max) = (hue_min.get(), hue_max.get());
+        let span = hue_span(min, max);
+        let preview_sv = preview_sv.get();
+        if min == 0.0 && span == 360.0 && preview_sv.is_none() {
+            return String::new();
+        }
+        let steps = (span / 60.0).ceil().max(1.0) as usize;
+        let stops = (0..=steps)
+            .map(|i| {
+                let progress = i as f64 / steps as f64;
+                let hue = min + span * progress;
+                let stop = match preview_sv {
+                    Some((saturation, value)) => {
+                        Color::from_hsva(hue as f32, saturation as f32, value as f32, 1.0)
+                            .to_hex_string()
+                    }
+                    None => format!("hsl({hue}, 100%, 50%)"),
+                };
+                format!("{stop} {}%", progress * 100.0)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("--lpc-hue-gradient: linear-gradient(to right, {stops});")
+    };
 
-    // Use the `use_position` hook to get the ref and handle_start function
-    let (ref_div, handle_start) = use_position(UsePositionProps {
-        on_move: handle_move.clone(),
+    let position = Signal::derive(move || {
+        value.map(|hue| hue_to_position(hue.get(), hue_min.get(), hue_max.get()))
+    });
+    let value_text = Callback::new(move |position: f64| {
+        let hue = hue_from_position(position, hue_min.get_untracked(), hue_max.get_untracked());
+        format!("{} degrees", hue.round())
     });
+
     view! {
-        <div class="leptos-color-hue-container" node_ref={ref_div} on:touchstart=move |ev| {
-            handle_start.run(ev.into())} on:mousedown=move |ev| {
-            handle_start.run(ev.into())}>
-            <div class="leptos-color-hue-pointer">
-                <div class="leptos-color-hue-slider" />
-            </div>
-        </div>
+        <Slider
+            value=position
+            label="Hue"
+            value_text=value_text
+            class="leptos-color-hue-container"
+            pointer_class="leptos-color-hue-pointer"
+            handle_class="leptos-color-hue-slider"
+            locked=locked
+            style=Signal::derive(gradient)
+            on_change=handle_move
+        />
+    }
+}
+
+/// Width of the hue range in degrees, handling wrap-around ranges like 330° to 30°.
+pub(crate) fn hue_span(min: f64, max: f64) -> f64 {
+    let span = (max - min).rem_euclid(360.0);
+    if span == 0.0 {
+        360.0
+    } else {
+        span
+    }
+}
+
+/// Maps a normalized slider position to a hue in degrees within `[min, max]`.
+pub(crate) fn hue_from_position(position: f64, min: f64, max: f64) -> f64 {
+    let hue = min + position * hue_span(min, max);
+    if hue > 360.0 {
+        hue - 360.0
+    } else {
+        hue
+    }
+}
+
+/// Maps a hue in degrees to a normalized slider position within `[min, max]`.
+///
+/// Hues outside of the range snap to the nearest end of the slider.
+pub(crate) fn hue_to_position(hue: f64, min: f64, max: f64) -> f64 {
+    let span = hue_span(min, max);
+    let offset = (hue - min).rem_euclid(360.0);
+    if offset <= span {
+        offset / span
+    } else if offset - span < 360.0 - offset {
+        1.0
+    } else {
+        0.0
     }
 }
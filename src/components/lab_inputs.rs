@@ -0,0 +1,94 @@
+use crate::components::color_picker::{handle_input_keydown, ChangeSource};
+use crate::format::{format_number, parse_decimal};
+use crate::lab::{clamp_to_srgb, lab_to_srgb_unclamped, srgb_to_lab};
+use csscolorparser::Color;
+use leptos::prelude::*;
+
+/// The CIE Lab inputs of a `ColorPicker`, available with the `lab` feature.
+///
+/// Edited values are converted to sRGB, clamped to its gamut and emitted with
+/// [`ChangeSource::LabInput`]. `gamut_clamped` is set whenever clamping was necessary, which
+/// shows a warning icon next to the inputs. The unclamped channels are stored in `raw_channels`
+/// for `on_change_raw`.
+#[component]
+pub(crate) fn LabInputs(
+    color: Signal<Color>,
+    emit: Callback<(Color, ChangeSource)>,
+    gamut_clamped: RwSignal<bool>,
+    raw_channels: StoredValue<Option<[f32; 4]>>,
+    decimal_separator: Signal<char>,
+    value_decimals: MaybeProp<u8>,
+) -> impl IntoView {
+    let lab = Memo::new(move |_| color.with(srgb_to_lab));
+
+    let on_lab_change = move |index: usize, value: f32| {
+        let color = color.get_untracked();
+        let mut lab = lab.get_untracked();
+        lab[index] = value;
+        let channels = lab_to_srgb_unclamped(lab);
+        let [r, g, b] = channels;
+        raw_channels.set_value(Some([r, g, b, color.a]));
+        let (color, clamped) = clamp_to_srgb(channels, color.a);
+        gamut_clamped.set(clamped);
+        emit.run((color, ChangeSource::LabInput));
+    };
+    let text = move |value: f32| {
+        format_number(
+            value,
+            value_decimals.get().unwrap_or(0),
+            decimal_separator.get(),
+        )
+    };
+    let lab_input = move |index: usize, label: &'static str, min: i32, max: i32| {
+        view! {
+            <label class="leptos-color-label">
+                <div class="leptos-color-wrapper">
+                    <input
+                        class="leptos-color-input"
+                        prop:value=move || text(lab.get()[index])
+                        on:keydown=move |ev| {
+                            handle_input_keydown(&ev, || untrack(|| text(lab.get()[index])))
+                        }
+                        name=format!("lab-{}", label.to_lowercase())
+                        type=move || if decimal_separator.get() == '.' { "number" } else { "text" }
+                        inputmode="decimal"
+                        style:width="42px"
+                        min=min
+                        max=max
+                        step="any"
+                        autocomplete="off"
+                        on:change=move |ev| {
+                            let value = parse_decimal(
+                                &event_target_value(&ev),
+                                decimal_separator.get_untracked(),
+                            );
+                            if let Some(value) = value {
+                                on_lab_change(index, value.clamp(min as f32, max as f32));
+                            }
+                        }
+                    />
+                </div>
+                <span>{label}</span>
+            </label>
+        }
+    };
+
+    view! {
+        <fieldset class="leptos-color-fieldset">
+            <legend class="leptos-color-visually-hidden">"CIE Lab"</legend>
+            {lab_input(0, "L", 0, 100)}
+            {lab_input(1, "a", -128, 127)}
+            {lab_input(2, "b", -128, 127)}
+        </fieldset>
+        <Show when=move || gamut_clamped.get()>
+            <span
+                class="leptos-color-gamut-warning"
+                role="img"
+                aria-label="clamped to sRGB"
+                title="clamped to sRGB"
+            >
+                "⚠"
+            </span>
+        </Show>
+    }
+}
@@ -2,5 +2,15 @@ pub mod alpha;
 #[cfg(feature = "color_input")]
 pub mod color_input;
 pub mod color_picker;
+#[cfg(feature = "color_input")]
+pub mod color_swatch_button;
+pub mod hsv_color_picker;
 pub mod hue;
+#[cfg(feature = "lab")]
+mod lab_inputs;
+pub mod orientation;
+#[cfg(feature = "color_input")]
+mod popover;
 pub mod saturation;
+pub mod slider;
+pub mod swatches;
@@ -0,0 +1,9 @@
+/// The axis along which a slider component is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// The slider runs from left to right.
+    #[default]
+    Horizontal,
+    /// The slider runs from bottom to top.
+    Vertical,
+}
@@ -0,0 +1,105 @@
+use crate::mount_style::mount_style;
+use floating_ui_leptos::{
+    use_floating, Flip, FlipOptions, MiddlewareVec, Offset, OffsetOptions, Placement,
+    UseFloatingOptions, UseFloatingReturn,
+};
+use leptos::{ev, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use web_sys::wasm_bindgen::JsCast as _;
+
+/// The floating container shared by the components that open a picker from a trigger.
+///
+/// The popover is positioned below `reference_ref` using `floating_ui_leptos` and closes
+/// when a click lands outside of both the trigger and the popover. The window click listener
+/// for that is only attached while the popover is open and `manage_outside_click` is set.
+/// `on_outside_click` runs just before such a click closes the popover.
+///
+/// Only the positioning and visibility are set inline. The appearance lives in `popover.css`
+/// on the `.color-picker-popover` class, so apps can override elevation, radius and the
+/// `z-index` (`1000` by default) with a more specific selector.
+#[component]
+pub(crate) fn PickerPopover(
+    reference_ref: AnyNodeRef,
+    open: ReadSignal<bool>,
+    set_open: WriteSignal<bool>,
+    #[prop(into, default = true.into())] manage_outside_click: Signal<bool>,
+    #[prop(into, optional)] on_outside_click: Option<Callback<()>>,
+    children: Children,
+) -> impl IntoView {
+    mount_style("PickerPopover", include_str!("./popover.css"));
+    let floating_ref = AnyNodeRef::new();
+
+    // Click outside detection. The window listener is only attached while the popover is
+    // open, so closed popovers cost nothing on page clicks.
+    let click_outside = StoredValue::new(None::<WindowListenerHandle>);
+    Effect::new(move |_| {
+        if !open.get() || !manage_outside_click.get() {
+            if let Some(handle) = click_outside.try_update_value(Option::take).flatten() {
+                handle.remove();
+            }
+            return;
+        }
+        if click_outside.with_value(Option::is_some) {
+            return;
+        }
+        let handle = window_event_listener(ev::click, move |ev| {
+            let target = ev.target();
+            let target_node = target.and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+
+            if let Some(target_node) = target_node {
+                if !reference_ref
+                    .get()
+                    .map(|r| r.contains(Some(&target_node)))
+                    .unwrap_or(false)
+                    && !floating_ref
+                        .get()
+                        .map(|f| f.contains(Some(&target_node)))
+                        .unwrap_or(false)
+                {
+                    if let Some(on_outside_click) = on_outside_click {
+                        on_outside_click.run(());
+                    }
+                    set_open.set(false);
+                }
+            }
+        });
+        click_outside.set_value(Some(handle));
+    });
+
+    let middleware: MiddlewareVec = vec![
+        Box::new(Offset::new(OffsetOptions::Value(8.0))), // Increased offset
+        Box::new(Flip::new(FlipOptions::default().cross_axis(false))),
+    ];
+
+    on_cleanup(move || {
+        if let Some(handle) = click_outside.try_update_value(Option::take).flatten() {
+            handle.remove();
+        }
+    });
+    let UseFloatingReturn {
+        floating_styles, ..
+    } = use_floating(
+        reference_ref,
+        floating_ref,
+        UseFloatingOptions::default()
+            .open(open.into())
+            .placement(Placement::Bottom.into())
+            .middleware(send_wrapper::SendWrapper::new(middleware).into())
+            .while_elements_mounted_auto_update(),
+    );
+    view! {
+        <div
+            node_ref=floating_ref
+            class="color-picker-popover"
+            style:display=move || if open.get() { "block" } else { "none" }
+            style:opacity=move || if open.get() { "1" } else { "0" }
+            style:position=move || floating_styles.get().style_position()
+            style:top=move || floating_styles.get().style_top()
+            style:left=move || floating_styles.get().style_left()
+            style:transform=move || floating_styles.get().style_transform().unwrap_or_default()
+            style:will-change=move || floating_styles.get().style_will_change().unwrap_or_default()
+        >
+            {children()}
+        </div>
+    }
+}
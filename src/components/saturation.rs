@@ -3,9 +3,14 @@ use leptos::logging::warn;
 use leptos::prelude::*;
 
 use crate::{
+    components::color_picker::ColorModel,
     hooks::use_position::{use_position, UsePositionProps},
     mount_style::mount_style,
 };
+
+/// How far above the finger `touch_offset` places the selection, in pixels.
+const TOUCH_OFFSET_PX: f64 = 32.0;
+
 /// A component for selecting color saturation and value.
 ///
 /// This component provides a 2D area where the user can select the saturation and value
@@ -17,20 +22,38 @@ use crate::{
 ///   The callback receives a tuple of (left, top) values, where both are in the range [0, 1].
 ///   - `left` represents the saturation (0 = unsaturated, 1 = fully saturated)
 ///   - `top` represents the value (0 = full value/brightness, 1 = no value/black)
+/// * `model`: An optional `Signal<ColorModel>`. With `ColorModel::Hsl` the area is rendered as HSL
+///   saturation (horizontal) and lightness (vertical, white at the top) instead of HSV.
+/// * `locked`: An optional `Signal<bool>` that keeps the control visible but ignores pointer input
+///   and dims it via the `leptos-color-locked` class.
+/// * `bordered`: An optional `Signal<bool>`, `true` by default. Draws a thin border in `--lpc-border-color`
+///   inside the area, so its white corner stays visible on light backgrounds. The border is drawn as an
+///   inset shadow and does not shift the coordinates passed to `on_change`.
+/// * `snap_resolution`: An optional `Signal<Option<u32>>`. With `Some(n)`, both coordinates are snapped
+///   to a grid of `n` steps before `on_change` runs, so a drag produces fewer distinct values and less
+///   downstream work on slow devices. Around `256` steps this stays visually smooth; lower values trade
+///   precision for fewer updates. Defaults to `None` (continuous).
+/// * `touch_offset`: An optional `Signal<bool>`. When set, touch drags select a point 32px above the
+///   finger, so the handle stays visible instead of hidden under it, as in native mobile pickers.
+///   The offset shrinks towards the bottom edge, which stays reachable. Mouse input is not affected.
 ///
 /// # Behavior
 ///
 /// - The component renders a square area with a white-to-transparent gradient overlaid on
 ///   a black-to-transparent gradient to create a saturation-value selection field.
 /// - Users can click, tap, or drag within this area to select a color.
+/// - The edges of the box map to exactly `0` and `1`, and positions beyond them are clamped. The
+///   pointer handle is centered on the position, so at the extremes it sits half outside the box.
 /// - The component uses the `use_position` hook to handle mouse and touch interactions.
+/// - The area can be focused from script (`tabindex="-1"`), e.g. by `ColorPicker`'s `autofocus`, but
+///   is not in the tab order.
 /// - As the user interacts with the component, the `on_change` callback is triggered with
 ///   the new position values.
 ///
 /// # Styling
 ///
 /// The component includes its own CSS styles, which are mounted using the `mount_style` function.
-/// It also injects additional styles for the saturation and value gradients.
+/// These include the saturation and value gradients, so no inline `<style>` is rendered.
 ///
 /// # Example
 ///
@@ -57,8 +80,16 @@ use crate::{
 ///
 /// This example creates a `Saturation` component and displays the selected saturation and value.
 #[component]
-pub fn Saturation(#[prop(into)] on_change: Callback<(f64, f64)>) -> impl IntoView {
+pub fn Saturation(
+    #[prop(into)] on_change: Callback<(f64, f64)>,
+    #[prop(into, optional)] model: Signal<ColorModel>,
+    #[prop(into, optional)] locked: Signal<bool>,
+    #[prop(into, optional)] snap_resolution: Signal<Option<u32>>,
+    #[prop(into, default = true.into())] bordered: Signal<bool>,
+    #[prop(into, optional)] touch_offset: Signal<bool>,
+) -> impl IntoView {
     mount_style("Saturation", include_str!("./saturation.css"));
+    mount_style("Slider", include_str!("./slider.css"));
     // Callback for position changes, updates the color based on left and top
     // let on_change = move |new_hsl: HSL| {
     //     set_hsl.set(new_hsl);
@@ -66,31 +97,48 @@ pub fn Saturation(#[prop(into)] on_change: Callback<(f64, f64)>) -> impl IntoVie
     //     log::info!("HSL updated: {:?}", new_hsl);
     // };
 
+    // Whether the current drag was started by a touch.
+    let touching = StoredValue::new(false);
+    let area_height = StoredValue::new(0.0_f64);
+
     // Closure that handles the position move
-    let handle_move = Callback::new(move |(left, top): (f64, f64)| on_change.run((left, top)));
+    let handle_move = Callback::new(move |(left, top): (f64, f64)| {
+        if locked.get_untracked() {
+            return;
+        }
+        let height = area_height.get_value();
+        let top = if touching.get_value() && touch_offset.get_untracked() && height > 0.0 {
+            (top - TOUCH_OFFSET_PX / height * (1.0 - top)).max(0.0)
+        } else {
+            top
+        };
+        match snap_resolution.get_untracked() {
+            Some(steps) if steps > 0 => {
+                let steps = f64::from(steps);
+                let snap = |value: f64| (value * steps).round() / steps;
+                on_change.run((snap(left), snap(top)));
+            }
+            _ => on_change.run((left, top)),
+        }
+    });
 
     // Use the `use_position` hook to get the ref and handle_start function
     let (ref_div, handle_start) = use_position(UsePositionProps {
         on_move: handle_move.clone(),
     });
     view! {
-        <div node_ref={ref_div} class="leptos-color-color" on:touchstart=move |ev| {
+        <div node_ref={ref_div} class="leptos-color-color" class:leptos-color-hsl=move || model.get() == ColorModel::Hsl class:leptos-color-locked=move || locked.get() class:leptos-color-bordered=move || bordered.get() tabindex="-1" on:touchstart=move |ev| {
+            touching.set_value(true);
+            if let Some(div) = ref_div.get_untracked() {
+                area_height.set_value(div.get_bounding_client_rect().height());
+            }
             handle_start.run(ev.into());} on:mousedown=move |ev| {
+            touching.set_value(false);
             handle_start.run(ev.into());}>
-            <style>r"
-            .saturation-white {
-                background: -webkit-linear-gradient(to right, #fff, rgba(255,255,255,0));
-                background: linear-gradient(to right, #fff, rgba(255,255,255,0));
-            }
-            .saturation-black {
-                background: -webkit-linear-gradient(to top, #000, rgba(0,0,0,0));
-                background: linear-gradient(to top, #000, rgba(0,0,0,0));
-            }
-            "</style>
             <div class="saturation-white leptos-color-gradient">
             <div class="saturation-black leptos-color-gradient" />
             <div class="leptos-color-pointer">
-                <div class="leptos-color-circle" />
+                <div class="leptos-color-handle leptos-color-circle" />
             </div>
             </div>
         </div>
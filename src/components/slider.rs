@@ -0,0 +1,161 @@
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::*;
+
+use crate::{
+    components::orientation::Orientation,
+    hooks::use_position::{use_position, UsePositionProps},
+    mount_style::mount_style,
+};
+
+/// A generic slider: a track with a draggable, keyboard-operable handle.
+///
+/// `Hue` and `Alpha` are thin wrappers around it that supply their gradients, and it can be used
+/// on its own for any other `0..=1` value, e.g. a lightness or opacity control next to a picker.
+///
+/// # Props
+///
+/// * `on_change`: A `Callback<(f64, f64)>` receiving `(along, across)`: the position along the slider,
+///   from `0` at the left (or bottom) to `1` at the right (or top), and the position across it.
+/// * `value`: An optional `MaybeProp<f64>` with the current position in `0..=1`. It places the handle,
+///   is exposed to assistive technology and is the starting point for keyboard steps. Without it the
+///   slider is pointer-only and the handle is placed by the `--lpc-slider-position` CSS variable.
+/// * `orientation`: An optional `Signal<Orientation>`. Defaults to `Orientation::Horizontal`.
+/// * `locked`: An optional `Signal<bool>` that keeps the slider visible but ignores input and dims it
+///   via the `leptos-color-locked` class.
+/// * `label`: The accessible name of the slider, e.g. `"Opacity"`.
+/// * `value_text`: An optional `Callback<f64, String>` producing the spoken value from `value`.
+///   Defaults to a percentage.
+/// * `style`: An optional `Signal<String>` with inline styles for the track, e.g. a `background`.
+/// * `class`, `vertical_class`, `pointer_class`, `handle_class`: Extra classes for the track (always,
+///   or only when vertical), the pointer and the handle, in addition to the shared
+///   `leptos-color-slider`, `leptos-color-slider-pointer` and `leptos-color-handle` classes.
+/// * `children`: Optional track layers drawn over the track's background, below the handle.
+///
+/// # Behavior
+///
+/// - The slider uses the `use_position` hook for mouse and touch interactions.
+/// - With a `value`, the slider is focusable with `role="slider"`. Arrow keys step it by 1% (10% with
+///   Shift), Home and End jump to the ends.
+///
+/// # Styling
+///
+/// The shared appearance lives in `slider.css`. The track thickness and handle size can be changed
+/// through the `--lpc-slider-thickness` and `--lpc-handle-size` CSS variables. While the slider has
+/// keyboard focus (`:focus-visible`), its handle is ringed in the theme's `--lpc-color`, in addition
+/// to the outline around the track. Pointer focus leaves the handle as it is.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_color::components::slider::Slider;
+///
+/// #[component]
+/// fn Opacity() -> impl IntoView {
+///     let opacity = RwSignal::new(0.5);
+///
+///     view! {
+///         <Slider
+///             value=Signal::from(opacity)
+///             label="Opacity"
+///             style="background: linear-gradient(to right, transparent, black);".to_string()
+///             on_change=move |(along, _)| opacity.set(along)
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn Slider(
+    #[prop(into)] on_change: Callback<(f64, f64)>,
+    #[prop(into, optional)] value: MaybeProp<f64>,
+    #[prop(into, optional)] orientation: Signal<Orientation>,
+    #[prop(into, optional)] locked: Signal<bool>,
+    #[prop(optional)] label: &'static str,
+    #[prop(into, optional)] value_text: Option<Callback<f64, String>>,
+    #[prop(into, optional)] style: Signal<String>,
+    #[prop(optional)] class: &'static str,
+    #[prop(optional)] vertical_class: &'static str,
+    #[prop(optional)] pointer_class: &'static str,
+    #[prop(optional)] handle_class: &'static str,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    mount_style("Slider", include_str!("./slider.css"));
+    let handle_move = Callback::new(move |(left, top): (f64, f64)| {
+        if locked.get_untracked() {
+            return;
+        }
+        match orientation.get_untracked() {
+            Orientation::Horizontal => on_change.run((left, top)),
+            Orientation::Vertical => on_change.run((1.0 - top, left)),
+        }
+    });
+    let (ref_div, handle_start) = use_position(UsePositionProps {
+        on_move: handle_move,
+    });
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let Some(current) = value.get_untracked() else {
+            return;
+        };
+        if locked.get_untracked() {
+            return;
+        }
+        let step = if ev.shift_key() { 0.1 } else { 0.01 };
+        let next = match ev.key().as_str() {
+            "ArrowRight" | "ArrowUp" => current + step,
+            "ArrowLeft" | "ArrowDown" => current - step,
+            "Home" => 0.0,
+            "End" => 1.0,
+            _ => return,
+        };
+        ev.prevent_default();
+        on_change.run((next.clamp(0.0, 1.0), 0.0));
+    };
+    let percent = move || value.get().map(|value| (value * 100.0).round());
+    let style = move || match percent() {
+        Some(percent) => format!("--lpc-slider-position: {percent}%; {}", style.get()),
+        None => style.get(),
+    };
+    // The ARIA attributes are only rendered for sliders with a value.
+    let has_value = move || value.get().is_some();
+
+    view! {
+        <div
+            class=move || match orientation.get() {
+                Orientation::Horizontal => format!("leptos-color-slider {class}"),
+                Orientation::Vertical => format!("leptos-color-slider {class} {vertical_class}"),
+            }
+            class:leptos-color-slider-vertical=move || orientation.get() == Orientation::Vertical
+            class:leptos-color-locked=move || locked.get()
+            style=style
+            role=move || has_value().then_some("slider")
+            tabindex=move || has_value().then_some("0")
+            aria-label=move || has_value().then_some(label)
+            aria-valuemin=move || has_value().then_some("0")
+            aria-valuemax=move || has_value().then_some("100")
+            aria-valuenow=move || percent().map(|percent| percent.to_string())
+            aria-valuetext=move || {
+                value.get().map(|current| match value_text {
+                    Some(value_text) => value_text.run(current),
+                    None => format!("{}%", (current * 100.0).round()),
+                })
+            }
+            aria-orientation=move || {
+                has_value().then(|| match orientation.get() {
+                    Orientation::Horizontal => "horizontal",
+                    Orientation::Vertical => "vertical",
+                })
+            }
+            aria-disabled=move || has_value().then(|| locked.get().to_string())
+            on:keydown=on_keydown
+            node_ref=ref_div
+            on:touchstart=move |ev| handle_start.run(ev.into())
+            on:mousedown=move |ev| handle_start.run(ev.into())
+        >
+            {children.map(|children| children())}
+            <div class=format!("leptos-color-slider-pointer {pointer_class}")>
+                <div class=format!("leptos-color-handle {handle_class}") />
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,176 @@
+use crate::contrast::readable_text_color;
+use crate::mount_style::mount_style;
+use csscolorparser::Color;
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::*;
+use web_sys::wasm_bindgen::JsCast as _;
+use web_sys::{Element, HtmlElement};
+
+/// A row of clickable color swatches.
+///
+/// This is the building block for preset palettes, color history and similar lists. It can also
+/// be used on its own, e.g. next to a `ColorPicker` or as a simple palette selector.
+///
+/// # Props
+///
+/// * `colors`: A `Signal<Vec<Color>>` with the colors to show, in order.
+/// * `selected`: An optional `Signal<Option<Color>>`. The swatch matching this color (compared as
+///   8-bit RGBA) is marked as selected with an outline and a check mark in black or white, whichever
+///   is more legible on the swatch.
+/// * `on_select`: A `Callback<Color>` called when a swatch is clicked or activated with the keyboard.
+/// * `scrollable`: An optional `Signal<bool>`. When set, swatches that do not fit the width stay on a
+///   single line that scrolls horizontally, snapping to whole swatches, instead of wrapping. Useful
+///   for long lists such as recently used colors.
+///
+/// # Accessibility
+///
+/// - The row is a `listbox` and each swatch an `option` labelled with its hex value, which is also
+///   shown as a tooltip.
+/// - Only one swatch is in the tab order at a time. Arrow keys move the focus between swatches
+///   (wrapping at the ends), Home and End jump to the first and last one.
+/// - Enter and Space select the focused swatch.
+/// - In a `scrollable` row, the focused swatch is scrolled into view.
+///
+/// Swatches can be dragged; they carry their hex value as `text/plain`, so they can be dropped
+/// onto the preview of a `ColorPicker` or any other text drop target.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_color::{components::swatches::Swatches, Color};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+///     let presets = vec![
+///         Color::from_rgba8(255, 0, 0, 255),
+///         Color::from_rgba8(0, 255, 0, 255),
+///         Color::from_rgba8(0, 0, 255, 255),
+///     ];
+///
+///     view! {
+///         <Swatches
+///             colors=presets
+///             selected=Signal::derive(move || Some(color.get()))
+///             on_select=move |new_color| color.set(new_color)
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn Swatches(
+    #[prop(into)] colors: Signal<Vec<Color>>,
+    #[prop(into, optional)] selected: Signal<Option<Color>>,
+    #[prop(into)] on_select: Callback<Color>,
+    #[prop(into, optional)] scrollable: Signal<bool>,
+) -> impl IntoView {
+    mount_style("Swatches", include_str!("./swatches.css"));
+    let (focused, set_focused) = signal(None::<usize>);
+
+    // The swatch that is reachable with Tab: the focused one, else the selected one.
+    let tab_stop = move || {
+        let count = colors.with(Vec::len);
+        if let Some(focused) = focused.get().filter(|focused| *focused < count) {
+            return focused;
+        }
+        selected
+            .get()
+            .and_then(|selected| {
+                colors.with(|colors| {
+                    colors
+                        .iter()
+                        .position(|color| color.to_rgba8() == selected.to_rgba8())
+                })
+            })
+            .unwrap_or(0)
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let Some(current) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+        else {
+            return;
+        };
+        let Some(parent) = current.parent_element() else {
+            return;
+        };
+        let next = match ev.key().as_str() {
+            "ArrowRight" | "ArrowDown" => current
+                .next_element_sibling()
+                .or_else(|| parent.first_element_child()),
+            "ArrowLeft" | "ArrowUp" => current
+                .previous_element_sibling()
+                .or_else(|| parent.last_element_child()),
+            "Home" => parent.first_element_child(),
+            "End" => parent.last_element_child(),
+            _ => return,
+        };
+        ev.prevent_default();
+        if let Some(next) = next.and_then(|next| next.dyn_into::<HtmlElement>().ok()) {
+            _ = next.focus();
+            if scrollable.get_untracked() {
+                next.scroll_into_view_with_bool(false);
+            }
+        }
+    };
+
+    view! {
+        <div
+            class="leptos-color-swatches"
+            class:leptos-color-swatches-scrollable=move || scrollable.get()
+            role="listbox"
+            aria-orientation="horizontal"
+            on:keydown=on_keydown
+        >
+            {move || {
+                let selected = selected.get().map(|color| color.to_rgba8());
+                colors
+                    .get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, color)| {
+                        let hex = color.to_hex_string();
+                        let is_selected = selected == Some(color.to_rgba8());
+                        view! {
+                            <button
+                                type="button"
+                                class="leptos-color-swatch"
+                                class:leptos-color-swatch-selected=is_selected
+                                role="option"
+                                aria-selected=is_selected.to_string()
+                                aria-label=hex.clone()
+                                title=hex.clone()
+                                tabindex=move || if tab_stop() == index { "0" } else { "-1" }
+                                on:focus=move |_| set_focused.set(Some(index))
+                                on:click=move |_| on_select.run(color)
+                                draggable="true"
+                                on:dragstart={
+                                    let hex = hex.clone();
+                                    move |ev| {
+                                        if let Some(data) = ev.data_transfer() {
+                                            _ = data.set_data("text/plain", &hex);
+                                            data.set_effect_allowed("copy");
+                                        }
+                                    }
+                                }
+                            >
+                                <span class="leptos-color-swatch-color" style:background=hex />
+                                {is_selected.then(|| view! {
+                                    <span
+                                        class="leptos-color-swatch-check"
+                                        aria-hidden="true"
+                                        style:color=readable_text_color(&color).to_hex_string()
+                                    >
+                                        "✓"
+                                    </span>
+                                })}
+                            </button>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,125 @@
+use crate::linear::{from_linear, to_linear};
+use csscolorparser::Color;
+
+/// The WCAG relative luminance of a [`Color`], from `0` (black) to `1` (white). Alpha is ignored.
+pub fn relative_luminance(color: &Color) -> f32 {
+    let [r, g, b] = [color.r, color.g, color.b].map(to_linear);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// The WCAG contrast ratio between two colors, from `1` (identical luminance) to `21`
+/// (black on white). Alpha is ignored.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{contrast::contrast_ratio, Color};
+///
+/// let black = Color::new(0.0, 0.0, 0.0, 1.0);
+/// let white = Color::new(1.0, 1.0, 1.0, 1.0);
+/// assert!((contrast_ratio(&black, &white) - 21.0).abs() < 0.01);
+/// ```
+pub fn contrast_ratio(a: &Color, b: &Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Black or white, whichever contrasts more with `background`, for text drawn on top of it.
+///
+/// Black is chosen from a relative luminance of about `0.179` upwards, where both reach the same
+/// contrast ratio. Alpha is ignored.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{contrast::readable_text_color, Color};
+///
+/// let yellow = Color::new(1.0, 1.0, 0.0, 1.0);
+/// assert_eq!(readable_text_color(&yellow).to_rgba8(), [0, 0, 0, 255]);
+/// ```
+pub fn readable_text_color(background: &Color) -> Color {
+    let black = Color::new(0.0, 0.0, 0.0, 1.0);
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    if contrast_ratio(background, &black) >= contrast_ratio(background, &white) {
+        black
+    } else {
+        white
+    }
+}
+
+/// A text color for `background` that reaches a contrast ratio of at least `min_ratio`, while
+/// staying as close to the background's tint as that allows.
+///
+/// The search runs in OKLCH, from [`readable_text_color`]'s black or white towards the background:
+/// the lightness moves towards the background's and the background's chroma and hue are blended
+/// in, as far as the ratio still holds. Ratios that even black or white cannot reach (above `21`,
+/// or for mid-gray backgrounds) yield plain black or white. Alpha is ignored and the result is opaque.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{contrast::{accessible_pair, contrast_ratio}, Color};
+///
+/// let teal = Color::from_rgba8(0, 128, 128, 255);
+/// let text = accessible_pair(&teal, 4.5);
+/// assert!(contrast_ratio(&teal, &text) >= 4.5);
+/// ```
+pub fn accessible_pair(background: &Color, min_ratio: f64) -> Color {
+    let extreme = readable_text_color(background);
+    let [lightness, a, b] = to_oklab(background);
+    let target = if extreme.r > 0.5 { 1.0 } else { 0.0 };
+    // `t = 0` is the background itself, `t = 1` the extreme.
+    let candidate = |t: f32| {
+        let chroma = 1.0 - t;
+        from_oklab([lightness + (target - lightness) * t, a * chroma, b * chroma])
+    };
+    let meets = |color: &Color| f64::from(contrast_ratio(background, color)) >= min_ratio;
+    if !meets(&extreme) {
+        return extreme;
+    }
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    if meets(&candidate(low)) {
+        return candidate(low);
+    }
+    for _ in 0..24 {
+        let middle = (low + high) / 2.0;
+        if meets(&candidate(middle)) {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    let color = candidate(high);
+    if meets(&color) {
+        color
+    } else {
+        extreme
+    }
+}
+
+/// Converts the sRGB channels of `color` to OKLab `[L, a, b]`.
+fn to_oklab(color: &Color) -> [f32; 3] {
+    let [r, g, b] = [color.r, color.g, color.b].map(to_linear);
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+/// Converts OKLab `[L, a, b]` to an opaque sRGB [`Color`], clamping channels outside the gamut.
+fn from_oklab([lightness, a, b]: [f32; 3]) -> Color {
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    let [r, g, b] = [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+    .map(|value| from_linear(value).clamp(0.0, 1.0));
+    Color::new(r, g, b, 1.0)
+}
@@ -0,0 +1,82 @@
+use crate::format::parse_color;
+use csscolorparser::Color;
+use web_sys::Element;
+
+/// How many nested `var()` fallbacks are followed before giving up.
+const MAX_DEPTH: usize = 8;
+
+/// Resolves a color that may reference a CSS custom property, e.g. `var(--brand-primary)`,
+/// against the computed style of `element`, then parses it with [`parse_color`].
+///
+/// Fallbacks such as `var(--brand-primary, #3b82f6)` are used when the property is unset or
+/// empty, and may themselves be `var()` references. Values without `var()` are parsed directly.
+///
+/// Returns `None` if the property cannot be resolved or its value is not a color. This reads
+/// computed styles, so it only works in the browser, after `element` is attached to the document.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use leptos_color::css_var::resolve_css_color;
+///
+/// let body = document().body().unwrap();
+/// let brand = resolve_css_color(&body, "var(--brand-primary, #3b82f6)");
+/// ```
+pub fn resolve_css_color(element: &Element, value: &str) -> Option<Color> {
+    let style = web_sys::window()?.get_computed_style(element).ok()??;
+    let mut value = value.trim().to_string();
+    for _ in 0..MAX_DEPTH {
+        let Some((name, fallback)) = parse_var(&value) else {
+            return parse_color(&value);
+        };
+        let resolved = style.get_property_value(name).unwrap_or_default();
+        value = match (resolved.trim(), fallback) {
+            ("", Some(fallback)) => fallback.to_string(),
+            ("", None) => return None,
+            (resolved, _) => resolved.to_string(),
+        };
+    }
+    None
+}
+
+/// Splits `var(--name)` or `var(--name, fallback)` into the property name and fallback.
+fn parse_var(value: &str) -> Option<(&str, Option<&str>)> {
+    let inner = value.strip_prefix("var(")?.strip_suffix(')')?;
+    let (name, fallback) = match inner.split_once(',') {
+        Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+        None => (inner.trim(), None),
+    };
+    name.starts_with("--").then_some((name, fallback))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_var;
+
+    #[test]
+    fn plain_values_are_not_references() {
+        assert_eq!(parse_var("#3b82f6"), None);
+        assert_eq!(parse_var("rgb(0, 0, 255)"), None);
+        assert_eq!(parse_var("var(brand)"), None);
+    }
+
+    #[test]
+    fn splits_the_name_and_fallback() {
+        assert_eq!(parse_var("var(--brand)"), Some(("--brand", None)));
+        assert_eq!(
+            parse_var("var( --brand , #3b82f6 )"),
+            Some(("--brand", Some("#3b82f6")))
+        );
+    }
+
+    #[test]
+    fn nested_fallbacks_are_kept_whole() {
+        let (name, fallback) = parse_var("var(--brand, var(--accent, rgb(0, 0, 255)))").unwrap();
+        assert_eq!(name, "--brand");
+        assert_eq!(fallback, Some("var(--accent, rgb(0, 0, 255))"));
+        assert_eq!(
+            parse_var(fallback.unwrap()),
+            Some(("--accent", Some("rgb(0, 0, 255)")))
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::linear::{from_linear, to_linear};
+use csscolorparser::Color;
+
+/// Linear sRGB to linear Display-P3 (both D65).
+const SRGB_TO_P3: [[f32; 3]; 3] = [
+    [0.822_462_1, 0.177_538, 0.0],
+    [0.033_194_1, 0.966_805_8, 0.0],
+    [0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+
+/// Linear Display-P3 to linear sRGB (both D65).
+const P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_1, -0.224_940_4, 0.0],
+    [-0.042_056_9, 1.042_057_1, 0.0],
+    [-0.019_637_6, -0.078_636_1, 1.098_273_5],
+];
+
+fn transform(matrix: &[[f32; 3]; 3], rgb: [f32; 3]) -> [f32; 3] {
+    let linear = rgb.map(to_linear);
+    matrix.map(|row| from_linear(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2]))
+}
+
+/// Converts an sRGB [`Color`] into Display-P3 channels.
+///
+/// Display-P3 shares the sRGB transfer function, so the conversion only changes
+/// the primaries. The result is `[r, g, b, a]` with every channel clamped to `[0, 1]`.
+///
+/// Colors are converted, not gamut-mapped: a value that falls outside the target
+/// gamut is simply clamped per channel.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{display_p3::srgb_to_display_p3, Color};
+///
+/// let [r, g, b, a] = srgb_to_display_p3(&Color::new(1.0, 0.0, 0.0, 1.0));
+/// ```
+pub fn srgb_to_display_p3(color: &Color) -> [f32; 4] {
+    let [r, g, b] = transform(&SRGB_TO_P3, [color.r, color.g, color.b]);
+    [r, g, b, color.a].map(|value| value.clamp(0.0, 1.0))
+}
+
+/// Converts Display-P3 channels back into an sRGB [`Color`].
+///
+/// P3 colors outside of the sRGB gamut are clamped per channel.
+pub fn display_p3_to_srgb(channels: [f32; 4]) -> Color {
+    let [r, g, b] = transform(&P3_TO_SRGB, [channels[0], channels[1], channels[2]]);
+    let [r, g, b, a] = [r, g, b, channels[3]].map(|value| value.clamp(0.0, 1.0));
+    Color::new(r, g, b, a)
+}
@@ -0,0 +1,170 @@
+use crate::color_ext::ColorExt as _;
+use crate::display_p3::{display_p3_to_srgb, srgb_to_display_p3};
+use csscolorparser::Color;
+
+/// The textual representation used when exporting a [`Color`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorFormat {
+    /// `#rrggbb`, or `#rrggbbaa` when the color is translucent.
+    #[default]
+    Hex,
+    /// `rgba(r, g, b, a)` with 8-bit channels and a `0..=1` alpha.
+    Rgba,
+    /// `color(display-p3 r g b / a)` for wide-gamut displays.
+    ///
+    /// The picker still edits in sRGB; the value is converted to Display-P3 on output.
+    /// See [`srgb_to_display_p3`] for details on clamping.
+    DisplayP3,
+}
+
+impl ColorFormat {
+    /// The largest per-channel difference (in `0..=1`) expected between a color and the result
+    /// of formatting and parsing it again, caused by the precision of the format.
+    pub fn tolerance(self) -> f32 {
+        match self {
+            // 8-bit quantization of every channel.
+            Self::Hex | Self::Rgba => 1.0 / 255.0,
+            // Four decimals, amplified slightly by the conversion back to sRGB.
+            Self::DisplayP3 => 1.0e-3,
+        }
+    }
+}
+
+/// Formats a [`Color`] as a CSS string in the given [`ColorFormat`].
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{format::{format_color, ColorFormat}, Color};
+///
+/// let red = Color::new(1.0, 0.0, 0.0, 1.0);
+/// assert_eq!(format_color(&red, ColorFormat::Hex), "#ff0000");
+/// ```
+pub fn format_color(color: &Color, format: ColorFormat) -> String {
+    match format {
+        ColorFormat::Hex => color.to_hex_string(),
+        ColorFormat::Rgba => color.to_rgba_css(),
+        ColorFormat::DisplayP3 => {
+            let [r, g, b, a] = srgb_to_display_p3(color);
+            format!(
+                "color(display-p3 {} {} {} / {})",
+                round4(r),
+                round4(g),
+                round4(b),
+                round4(a)
+            )
+        }
+    }
+}
+
+/// Parses a CSS color string in any format accepted by `csscolorparser`, plus the
+/// `color(display-p3 r g b / a)` syntax produced by [`ColorFormat::DisplayP3`].
+///
+/// Returns `None` if the string is not a valid color.
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    if let Some(channels) = value
+        .strip_prefix("color(display-p3")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        return parse_display_p3(channels);
+    }
+    value.parse::<Color>().ok()
+}
+
+/// Returns whether `color` survives [`format_color`] followed by [`parse_color`] in the given
+/// format, within [`ColorFormat::tolerance`].
+///
+/// Every [`ColorFormat`] is expected to round-trip all sRGB colors.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{format::{round_trips, ColorFormat}, Color};
+///
+/// assert!(round_trips(&Color::new(0.2, 0.4, 0.6, 0.5), ColorFormat::DisplayP3));
+/// ```
+pub fn round_trips(color: &Color, format: ColorFormat) -> bool {
+    let Some(parsed) = parse_color(&format_color(color, format)) else {
+        return false;
+    };
+    let tolerance = format.tolerance();
+    [
+        (color.r, parsed.r),
+        (color.g, parsed.g),
+        (color.b, parsed.b),
+        (color.a, parsed.a),
+    ]
+    .iter()
+    .all(|(expected, actual)| (expected - actual).abs() <= tolerance)
+}
+
+/// Parses a decimal number written with the given decimal separator, e.g. `0,5` with `','`.
+///
+/// A `.` is accepted regardless of the separator, so pasted values keep working.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::format::parse_decimal;
+///
+/// assert_eq!(parse_decimal("0,5", ','), Some(0.5));
+/// assert_eq!(parse_decimal("0.5", ','), Some(0.5));
+/// ```
+pub fn parse_decimal(value: &str, separator: char) -> Option<f32> {
+    value.trim().replace(separator, ".").parse().ok()
+}
+
+/// Replaces the `.` decimal separator of a formatted number with `separator`.
+pub fn format_decimal(value: &str, separator: char) -> String {
+    value.replace('.', &separator.to_string())
+}
+
+/// Formats a number for the fractional inputs with a fixed number of decimals and `separator`.
+///
+/// The result never contains grouping separators, which would break parsing it back with
+/// [`parse_decimal`], and values that round to zero are written without a sign.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::format::format_number;
+///
+/// assert_eq!(format_number(1234.5, 2, ','), "1234,50");
+/// assert_eq!(format_number(-0.01, 1, '.'), "0.0");
+/// ```
+pub fn format_number(value: f32, decimals: u8, separator: char) -> String {
+    let formatted = format!("{value:.*}", usize::from(decimals));
+    let formatted = match formatted.strip_prefix('-') {
+        Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => unsigned.to_string(),
+        _ => formatted,
+    };
+    format_decimal(&formatted, separator)
+}
+
+fn parse_display_p3(channels: &str) -> Option<Color> {
+    let (rgb, alpha) = match channels.split_once('/') {
+        Some((rgb, alpha)) => (rgb, Some(alpha)),
+        None => (channels, None),
+    };
+    let parse_channel = |value: &str| match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok().map(|v| v / 100.0),
+        None => value.parse::<f32>().ok(),
+    };
+    let rgb = rgb
+        .split_whitespace()
+        .map(parse_channel)
+        .collect::<Option<Vec<_>>>()?;
+    let [r, g, b] = rgb[..] else {
+        return None;
+    };
+    let a = match alpha {
+        Some(alpha) => parse_channel(alpha.trim())?,
+        None => 1.0,
+    };
+    Some(display_p3_to_srgb([r, g, b, a]))
+}
+
+fn round4(value: f32) -> f32 {
+    (value * 10_000.0).round() / 10_000.0
+}
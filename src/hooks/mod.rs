@@ -1 +1,2 @@
 pub mod use_position;
+pub mod use_scrub;
@@ -14,6 +14,12 @@ enum MoveType {
     Mouse,
     Touch,
 }
+/// Returns the `(left, top, width, height)` of `div`'s current bounding rect.
+fn measure(div: &web_sys::HtmlDivElement) -> (f64, f64, f64, f64) {
+    let rect = Element::from(div.deref().clone()).get_bounding_client_rect();
+    (rect.left(), rect.top(), rect.width(), rect.height())
+}
+
 /// A custom hook for handling position-based interactions in a component.
 ///
 /// This hook provides functionality for tracking and responding to mouse and touch
@@ -46,9 +52,20 @@ enum MoveType {
 ///
 /// - Tracks mouse and touch interactions within the target element.
 /// - Normalizes the position to values between 0 and 1 for both x and y coordinates.
+/// - Measures the element's bounding rect on every event instead of caching it, so resizing,
+///   moving or animating the element in the middle of a drag never maps the pointer with a stale rect.
 /// - Handles dragging behavior, including starting, moving, and ending drag operations.
+/// - The release position (`mouseup` or `touchend`) is reported as well when it differs from the last
+///   reported one, so a fast flick that ends before another `mousemove` is sampled still comes to rest
+///   exactly where the pointer was released.
+/// - Every `mousemove` and `touchmove` is reported as it arrives; nothing is throttled or dropped.
+///   Browsers dispatch a `mousemove` for each `pointermove` of a mouse, so listening to pointer events
+///   as well would only report each position twice.
 /// - Attaches necessary event listeners dynamically when dragging starts and removes them when it ends.
 /// - Works with both mouse and touch events for broad device compatibility.
+/// - In the crate's own browser tests, `test_support` can replace the measured rect with a synthetic
+///   one or feed normalized positions straight to `on_move`, so drags can be simulated without
+///   a real layout. It is not compiled into other builds.
 ///
 /// # Example
 ///
@@ -86,15 +103,25 @@ pub fn use_position(props: UsePositionProps) -> (NodeRef<Div>, Callback<UiEvent>
 
     let limit = |value: f64| -> f64 { value.min(1.0).max(0.0) };
 
-    let get_position = move |e: &Event| -> Option<(f64, f64)> {
-        if let Some(div) = ref_div.get_untracked() {
-            let rect = Element::from(div.deref().clone()).get_bounding_client_rect();
-            let (width, height) = (rect.width(), rect.height());
+    #[cfg(all(test, target_arch = "wasm32"))]
+    test_support::register(props.on_move);
 
+    let get_position = move |e: &Event| -> Option<(f64, f64)> {
+        #[cfg(all(test, target_arch = "wasm32"))]
+        let rect =
+            test_support::rect().or_else(|| ref_div.get_untracked().map(|div| measure(&div)));
+        #[cfg(not(all(test, target_arch = "wasm32")))]
+        let rect = ref_div.get_untracked().map(|div| measure(&div));
+        if let Some((left, top, width, height)) = rect {
             let (client_x, client_y) = if let Some(mouse_event) = e.dyn_ref::<MouseEvent>() {
                 (mouse_event.client_x() as f64, mouse_event.client_y() as f64)
             } else if let Some(touch_event) = e.dyn_ref::<TouchEvent>() {
-                if let Some(touch) = touch_event.touches().item(0) {
+                // A `touchend` has no remaining touches, only the one that was lifted.
+                let touch = touch_event
+                    .touches()
+                    .item(0)
+                    .or_else(|| touch_event.changed_touches().item(0));
+                if let Some(touch) = touch {
                     (touch.client_x() as f64, touch.client_y() as f64)
                 } else {
                     return None;
@@ -103,35 +130,46 @@ pub fn use_position(props: UsePositionProps) -> (NodeRef<Div>, Callback<UiEvent>
                 return None;
             };
             Some((
-                limit((client_x - rect.left()) / width),
-                limit((client_y - rect.top()) / height),
+                limit((client_x - left) / width),
+                limit((client_y - top) / height),
             ))
         } else {
             None
         }
     };
 
-    let handle_move = {
-        let on_move = props.on_move.clone();
-        move |move_type: MoveType, e: Event| {
-            if matches!(move_type, MoveType::Mouse) {
-                e.prevent_default();
-            }
-            if let Some(pos) = get_position(&e) {
-                on_move.run(pos);
-            }
+    // The last position passed to `on_move` during the current drag.
+    let last_position = StoredValue::new(None::<(f64, f64)>);
+    let on_move = props.on_move;
+    let report = move |pos: (f64, f64)| {
+        last_position.set_value(Some(pos));
+        on_move.run(pos);
+    };
+
+    let handle_move = move |move_type: MoveType, e: Event| {
+        if matches!(move_type, MoveType::Mouse) {
+            e.prevent_default();
+        }
+        if let Some(pos) = get_position(&e) {
+            report(pos);
         }
     };
 
     let handle_start = move |e: UiEvent| {
         set_dragging.set(true);
+        last_position.set_value(None);
         if let Some(pos) = get_position(&e) {
-            props.on_move.run(pos);
+            report(pos);
         }
     };
 
-    let handle_end = move || {
+    let handle_end = move |e: Event| {
         set_dragging.set(false);
+        if let Some(pos) = get_position(&e) {
+            if last_position.get_value() != Some(pos) {
+                report(pos);
+            }
+        }
     };
 
     create_effect(move |_| {
@@ -140,17 +178,87 @@ pub fn use_position(props: UsePositionProps) -> (NodeRef<Div>, Callback<UiEvent>
             let _ = use_event_listener(use_document(), mousemove, move |evt| {
                 handle_move(MoveType::Mouse, evt.into());
             });
-            let _ = use_event_listener(use_document(), mouseup, move |_| {
-                handle_end();
+            let _ = use_event_listener(use_document(), mouseup, move |evt| {
+                handle_end(evt.into());
             });
             let _ = use_event_listener(use_document(), touchmove, move |evt| {
                 handle_move(MoveType::Touch, evt.into());
             });
-            let _ = use_event_listener(use_document(), touchend, move |_| {
-                handle_end();
+            let _ = use_event_listener(use_document(), touchend, move |evt| {
+                handle_end(evt.into());
             });
         };
     });
 
     (ref_div, Callback::new(handle_start))
 }
+
+/// Hooks for unit tests to drive `use_position` without a real layout.
+#[cfg(all(test, target_arch = "wasm32"))]
+pub(crate) mod test_support {
+    use leptos::prelude::*;
+    use std::cell::{Cell, RefCell};
+
+    thread_local! {
+        static RECT: Cell<Option<(f64, f64, f64, f64)>> = const { Cell::new(None) };
+        static ON_MOVE: RefCell<Vec<Callback<(f64, f64)>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn register(on_move: Callback<(f64, f64)>) {
+        ON_MOVE.with_borrow_mut(|callbacks| callbacks.push(on_move));
+    }
+
+    pub(super) fn rect() -> Option<(f64, f64, f64, f64)> {
+        RECT.get()
+    }
+
+    /// Makes every `use_position` map pointer events against `(left, top, width, height)`
+    /// instead of the measured bounding rect, or against the real rect again for `None`.
+    pub(crate) fn set_rect(rect: Option<(f64, f64, f64, f64)>) {
+        RECT.set(rect);
+    }
+
+    /// Feeds the normalized `position` to the `index`-th `use_position` created since the last
+    /// [`reset`], clamped to `[0, 1]` like pointer positions.
+    pub(crate) fn move_to(index: usize, (x, y): (f64, f64)) {
+        let on_move = ON_MOVE.with_borrow(|callbacks| callbacks[index]);
+        on_move.run((x.clamp(0.0, 1.0), y.clamp(0.0, 1.0)));
+    }
+
+    /// Forgets the registered hooks and the synthetic rect.
+    pub(crate) fn reset() {
+        RECT.set(None);
+        ON_MOVE.with_borrow_mut(Vec::clear);
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::MouseEventInit;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn maps_pointer_against_synthetic_rect() {
+        Owner::new().with(|| {
+            test_support::reset();
+            test_support::set_rect(Some((100.0, 50.0, 200.0, 100.0)));
+            let position = RwSignal::new(None);
+            let (_, handle_start) = use_position(UsePositionProps {
+                on_move: Callback::new(move |pos| position.set(Some(pos))),
+            });
+
+            let init = MouseEventInit::new();
+            init.set_client_x(150);
+            init.set_client_y(75);
+            let event = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+            handle_start.run(event.into());
+            assert_eq!(position.get_untracked(), Some((0.25, 0.25)));
+
+            test_support::move_to(0, (1.5, -0.5));
+            assert_eq!(position.get_untracked(), Some((1.0, 0.0)));
+        });
+    }
+}
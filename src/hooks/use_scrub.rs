@@ -0,0 +1,125 @@
+use leptos::ev::{mousemove, mouseup, touchend, touchmove, Event, UiEvent};
+use leptos::prelude::*;
+use leptos_use::{use_document, use_event_listener};
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{MouseEvent, TouchEvent};
+
+/// Horizontal distance in pixels the pointer has to travel for a single step.
+const PIXELS_PER_STEP: f64 = 2.0;
+
+#[derive(Clone)]
+pub struct UseScrubProps {
+    pub enabled: Signal<bool>,
+    pub on_scrub: Callback<i32, ()>,
+}
+
+/// A custom hook for "scrubbing" a numeric value by dragging horizontally.
+///
+/// Unlike [`use_position`](super::use_position::use_position), which reports the
+/// absolute pointer position inside an element, this hook tracks the distance the
+/// pointer travelled since the last event and reports it as whole steps.
+///
+/// # Arguments
+///
+/// * `props`: `UseScrubProps` - A struct containing the configuration for the hook.
+///
+/// # UseScrubProps
+///
+/// * `enabled`: While `false`, the start callback does nothing so the element keeps
+///   its default behavior (e.g. text selection in an input).
+/// * `on_scrub`: A callback receiving the signed number of steps travelled since the
+///   previous call. Dragging to the right yields positive steps.
+///
+/// # Returns
+///
+/// A `Callback<UiEvent>` that should be used to handle the start of an interaction
+/// (mousedown or touchstart).
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+///
+/// #[component]
+/// fn Scrubber() -> impl IntoView {
+///     let value = RwSignal::new(0);
+///     let handle_start = use_scrub(UseScrubProps {
+///         enabled: Signal::from(true),
+///         on_scrub: Callback::new(move |steps| value.update(|v| *v += steps)),
+///     });
+///
+///     view! {
+///         <span on:mousedown=move |ev| handle_start.run(ev.into())>{value}</span>
+///     }
+/// }
+/// ```
+pub fn use_scrub(props: UseScrubProps) -> Callback<UiEvent> {
+    let (dragging, set_dragging) = signal(false);
+    let last_x = StoredValue::new(0.0_f64);
+    let remainder = StoredValue::new(0.0_f64);
+
+    let get_x = move |e: &Event| -> Option<f64> {
+        if let Some(mouse_event) = e.dyn_ref::<MouseEvent>() {
+            Some(mouse_event.client_x() as f64)
+        } else if let Some(touch_event) = e.dyn_ref::<TouchEvent>() {
+            touch_event
+                .touches()
+                .item(0)
+                .map(|touch| touch.client_x() as f64)
+        } else {
+            None
+        }
+    };
+
+    let handle_move = {
+        let on_scrub = props.on_scrub;
+        move |e: Event| {
+            let Some(x) = get_x(&e) else {
+                return;
+            };
+            e.prevent_default();
+            let travelled = remainder.get_value() + x - last_x.get_value();
+            last_x.set_value(x);
+            let steps = (travelled / PIXELS_PER_STEP).trunc();
+            remainder.set_value(travelled - steps * PIXELS_PER_STEP);
+            if steps != 0.0 {
+                on_scrub.run(steps as i32);
+            }
+        }
+    };
+
+    let handle_start = move |e: UiEvent| {
+        if !props.enabled.get_untracked() {
+            return;
+        }
+        if let Some(x) = get_x(&e) {
+            e.prevent_default();
+            last_x.set_value(x);
+            remainder.set_value(0.0);
+            set_dragging.set(true);
+        }
+    };
+
+    let handle_end = move || {
+        set_dragging.set(false);
+    };
+
+    Effect::new(move |_| {
+        if dragging.get() {
+            let _ = use_event_listener(use_document(), mousemove, move |evt| {
+                handle_move(evt.into());
+            });
+            let _ = use_event_listener(use_document(), mouseup, move |_| {
+                handle_end();
+            });
+            let _ = use_event_listener(use_document(), touchmove, move |evt| {
+                handle_move(evt.into());
+            });
+            let _ = use_event_listener(use_document(), touchend, move |_| {
+                handle_end();
+            });
+        }
+    });
+
+    Callback::new(handle_start)
+}
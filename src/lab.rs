@@ -0,0 +1,128 @@
+use crate::linear::{from_linear, to_linear};
+use csscolorparser::Color;
+
+/// The D65 reference white in XYZ, normalized to `Y = 1`.
+const WHITE_D65: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// Linear sRGB to XYZ (D65).
+const SRGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.412_456_4, 0.357_576_1, 0.180_437_5],
+    [0.212_672_9, 0.715_152_2, 0.072_175],
+    [0.019_333_9, 0.119_192, 0.950_304_1],
+];
+
+/// XYZ (D65) to linear sRGB.
+const XYZ_TO_SRGB: [[f32; 3]; 3] = [
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266, 1.876_010_8, 0.041_556],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+];
+
+const EPSILON: f32 = 216.0 / 24389.0;
+const KAPPA: f32 = 24389.0 / 27.0;
+
+fn multiply(matrix: &[[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// Converts an sRGB [`Color`] to CIE XYZ (D65). Alpha is ignored.
+pub fn srgb_to_xyz(color: &Color) -> [f32; 3] {
+    multiply(&SRGB_TO_XYZ, [color.r, color.g, color.b].map(to_linear))
+}
+
+/// Converts CIE XYZ (D65) to sRGB channels.
+///
+/// The channels are not clamped and fall outside of `[0, 1]` for colors outside
+/// of the sRGB gamut.
+pub fn xyz_to_srgb(xyz: [f32; 3]) -> [f32; 3] {
+    multiply(&XYZ_TO_SRGB, xyz).map(from_linear)
+}
+
+/// Converts an sRGB [`Color`] to CIE Lab (D65) as `[L, a, b]`. Alpha is ignored.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{lab::srgb_to_lab, Color};
+///
+/// let [l, a, b] = srgb_to_lab(&Color::new(1.0, 1.0, 1.0, 1.0));
+/// assert!((l - 100.0).abs() < 0.01 && a.abs() < 0.01 && b.abs() < 0.01);
+/// ```
+pub fn srgb_to_lab(color: &Color) -> [f32; 3] {
+    let f = |t: f32| {
+        if t > EPSILON {
+            t.cbrt()
+        } else {
+            (KAPPA * t + 16.0) / 116.0
+        }
+    };
+    let xyz = srgb_to_xyz(color);
+    let [fx, fy, fz] = [0, 1, 2].map(|i| f(xyz[i] / WHITE_D65[i]));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Converts CIE Lab (D65) to sRGB channels without clamping.
+pub fn lab_to_srgb_unclamped(lab: [f32; 3]) -> [f32; 3] {
+    let [l, a, b] = lab;
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    let inverse = |f: f32| {
+        let cubed = f.powi(3);
+        if cubed > EPSILON {
+            cubed
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+    let y = if l > KAPPA * EPSILON {
+        fy.powi(3)
+    } else {
+        l / KAPPA
+    };
+    let xyz = [
+        inverse(fx) * WHITE_D65[0],
+        y * WHITE_D65[1],
+        inverse(fz) * WHITE_D65[2],
+    ];
+    xyz_to_srgb(xyz)
+}
+
+/// Returns whether the sRGB channels lie within `[0, 1]`, i.e. no clamping is needed.
+pub fn in_srgb_gamut(channels: [f32; 3]) -> bool {
+    channels.iter().all(|value| (0.0..=1.0).contains(value))
+}
+
+/// Converts CIE Lab (D65) to an sRGB [`Color`] with the given alpha, together with whether
+/// any channel had to be clamped to fit the sRGB gamut.
+pub fn lab_to_srgb_checked(lab: [f32; 3], alpha: f32) -> (Color, bool) {
+    clamp_to_srgb(lab_to_srgb_unclamped(lab), alpha)
+}
+
+/// Clamps the channels of [`lab_to_srgb_unclamped`] into a [`Color`], together with whether
+/// any channel had to be clamped.
+pub(crate) fn clamp_to_srgb(channels: [f32; 3], alpha: f32) -> (Color, bool) {
+    let [r, g, b] = channels.map(|value| value.clamp(0.0, 1.0));
+    (Color::new(r, g, b, alpha), !in_srgb_gamut(channels))
+}
+
+/// Converts CIE Lab (D65) to an sRGB [`Color`] with the given alpha.
+///
+/// Colors outside of the sRGB gamut are clamped per channel. Use [`lab_to_srgb_checked`]
+/// to find out whether that happened.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{lab::{lab_to_srgb, srgb_to_lab}, Color};
+///
+/// // sRGB red is L = 53.24, a = 80.09, b = 67.20.
+/// let red = lab_to_srgb([53.24, 80.09, 67.20], 1.0);
+/// assert_eq!(red.to_rgba8(), [255, 0, 0, 255]);
+///
+/// let teal = Color::from_rgba8(0, 128, 128, 255);
+/// assert_eq!(lab_to_srgb(srgb_to_lab(&teal), 1.0).to_rgba8(), teal.to_rgba8());
+/// ```
+pub fn lab_to_srgb(lab: [f32; 3], alpha: f32) -> Color {
+    lab_to_srgb_checked(lab, alpha).0
+}
@@ -1,5 +1,18 @@
+pub mod color_ext;
 pub mod components;
+pub mod contrast;
+pub mod css_var;
+pub mod display_p3;
+pub mod format;
 pub mod hooks;
+#[cfg(feature = "lab")]
+pub mod lab;
+pub mod linear;
 mod mount_style;
+pub mod names;
+pub mod palette;
+#[cfg(feature = "palettes")]
+pub mod palettes;
+pub mod prelude;
 pub use csscolorparser::Color;
 pub mod theme;
@@ -0,0 +1,37 @@
+use csscolorparser::Color;
+
+/// Converts an sRGB-encoded channel to linear light.
+pub(crate) fn to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear-light channel back to its sRGB encoding.
+pub(crate) fn from_linear(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Composites `color` over the opaque `background` in linear light and returns the
+/// resulting opaque color.
+///
+/// Plain CSS blends translucent colors in gamma-encoded sRGB, which makes them look
+/// darker than they physically are. Blending in linear light matches how the light
+/// of the two colors actually mixes.
+pub fn composite_linear(color: &Color, background: &Color) -> Color {
+    let blend = |fg: f32, bg: f32| {
+        from_linear(to_linear(fg) * color.a + to_linear(bg) * (1.0 - color.a)).clamp(0.0, 1.0)
+    };
+    Color::new(
+        blend(color.r, background.r),
+        blend(color.g, background.g),
+        blend(color.b, background.b),
+        1.0,
+    )
+}
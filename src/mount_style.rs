@@ -2,8 +2,14 @@ use cfg_if::cfg_if;
 use html::ElementChild as _;
 use leptos::*;
 use prelude::CustomAttribute;
+/// Injects `content` as a `<style id="leptos-color-id-{id}">` element, once per id.
+///
+/// For apps with a `style-src 'nonce-...'` Content Security Policy, the style element carries
+/// the nonce provided through Leptos' nonce context (`leptos::nonce::provide_nonce`, or
+/// the `nonce` feature of the server integrations), so the injected styles are allowed.
 pub fn mount_style(id: &str, content: &'static str) {
     let id = format!("leptos-color-id-{id}");
+    let nonce = nonce::use_nonce().map(|nonce| nonce.to_string());
     cfg_if! {
         if #[cfg(feature = "ssr")] {
             use leptos::view;
@@ -14,10 +20,19 @@ pub fn mount_style(id: &str, content: &'static str) {
                 return;
             }
 
-            let _ = view! {
-                <Style id=id>
-                    {content}
-                </Style>
+            let _ = match nonce {
+                Some(nonce) => view! {
+                    <Style id=id nonce=nonce>
+                        {content}
+                    </Style>
+                }
+                .into_any(),
+                None => view! {
+                    <Style id=id>
+                        {content}
+                    </Style>
+                }
+                .into_any(),
             };
         } else {
             use leptos::prelude::document;
@@ -34,6 +49,9 @@ pub fn mount_style(id: &str, content: &'static str) {
                 .create_element("style")
                 .expect("create style element error");
             _ = style.set_attribute("id", &id);
+            if let Some(nonce) = nonce {
+                _ = style.set_attribute("nonce", &nonce);
+            }
             style.set_text_content(Some(content));
             _ = head.prepend_with_node_1(&style);
         }
@@ -58,6 +76,7 @@ where
     Chil: IntoView + 'static,
 {
     let context = SSRMountStyleContext::default();
+    context.set_nonce(nonce::use_nonce().map(|nonce| nonce.to_string()));
 
     let children = Provider(
         ProviderProps::builder()
@@ -72,6 +91,7 @@ where
 #[derive(Debug, Clone)]
 pub struct SSRMountStyleContext {
     styles: ArcStoredValue<HashMap<String, String>>,
+    nonce: ArcStoredValue<Option<String>>,
 }
 
 impl SSRMountStyleContext {
@@ -83,30 +103,45 @@ impl SSRMountStyleContext {
         self.styles.write_value().insert(k, v);
     }
 
+    fn set_nonce(&self, nonce: Option<String>) {
+        *self.nonce.write_value() = nonce;
+    }
+
     fn default() -> Self {
         Self {
             styles: Default::default(),
+            nonce: Default::default(),
         }
     }
 
+    fn nonce_attribute(&self) -> String {
+        self.nonce
+            .read_value()
+            .as_ref()
+            .map(|nonce| format!(r#" nonce="{nonce}""#))
+            .unwrap_or_default()
+    }
+
     fn html_len(&self) -> usize {
         const TEMPLATE_LEN: usize = r#"<style id=""></style>"#.len();
+        let nonce_len = self.nonce_attribute().len();
         let mut html_len = 0;
         let styles = self.styles.write_value();
 
         styles.iter().for_each(|(k, v)| {
-            html_len += k.len() + v.len() + TEMPLATE_LEN;
+            html_len += k.len() + v.len() + TEMPLATE_LEN + nonce_len;
         });
 
         html_len
     }
 
     fn to_html(self) -> String {
+        let nonce = self.nonce_attribute();
         let mut styles = self.styles.write_value();
         styles
             .drain()
             .into_iter()
-            .map(|(k, v)| format!(r#"<style id="{k}">{v}</style>"#))
+            .map(|(k, v)| format!(r#"<style id="{k}"{nonce}>{v}</style>"#))
             .collect::<String>()
     }
 }
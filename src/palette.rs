@@ -0,0 +1,201 @@
+use csscolorparser::Color;
+
+/// Embeds a palette file into the binary and parses it into named colors at runtime.
+///
+/// Only the reading happens at compile time, through `include_str!`, so no fetch is needed.
+/// The parsing runs each time the macro is evaluated. The path is relative to the file invoking
+/// the macro. See [`parse_palette`] for the supported formats.
+///
+/// # Panics
+///
+/// Panics, where the macro is evaluated, when the palette file is malformed or contains an
+/// invalid color. A bad file still builds; call [`parse_palette`] with `include_str!` instead
+/// to handle the error yourself.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use leptos_color::load_palette;
+///
+/// // tokens.json: { "primary": "#3b82f6", "danger": "rgb(239, 68, 68)" }
+/// let swatches: Vec<(String, Color)> = load_palette!("tokens.json");
+/// ```
+#[macro_export]
+macro_rules! load_palette {
+    ($path:literal) => {
+        $crate::palette::parse_palette(include_str!($path))
+            .unwrap_or_else(|err| panic!("invalid palette file {}: {}", $path, err))
+    };
+}
+
+/// Parses a palette of named colors.
+///
+/// Two flat formats are supported:
+///
+/// - JSON, a single object mapping names to color strings:
+///   `{ "primary": "#3b82f6", "danger": "red" }`
+/// - TOML, one `name = "color"` pair per line. Comment lines, trailing `# comments` and
+///   `[table]` headers are skipped.
+///
+/// Color values can be anything `csscolorparser` understands. The entries are returned in
+/// file order.
+///
+/// # Errors
+///
+/// Returns a [`PaletteParseError`] if the file does not follow either format or a
+/// color value cannot be parsed.
+pub fn parse_palette(source: &str) -> Result<Vec<(String, Color)>, PaletteParseError> {
+    let entries = if source.trim_start().starts_with('{') {
+        parse_json(source)?
+    } else {
+        parse_toml(source)?
+    };
+    entries
+        .into_iter()
+        .map(|(name, value)| match value.parse::<Color>() {
+            Ok(color) => Ok((name, color)),
+            Err(_) => Err(PaletteParseError::InvalidColor { name, value }),
+        })
+        .collect()
+}
+
+fn parse_json(source: &str) -> Result<Vec<(String, String)>, PaletteParseError> {
+    let mut chars = source.trim().chars().peekable();
+    let mut entries = Vec::new();
+    let syntax = |message: &str| PaletteParseError::Syntax(message.to_string());
+
+    let skip_whitespace = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    };
+    let read_string = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        if chars.next() != Some('"') {
+            return Err(syntax("expected a string"));
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => value.push(match chars.next() {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('u') => read_unicode_escape(chars)?,
+                    Some(other) => {
+                        return Err(PaletteParseError::Syntax(format!(
+                            "invalid escape `\\{other}`"
+                        )))
+                    }
+                    None => return Err(syntax("unterminated string")),
+                }),
+                Some(c) => value.push(c),
+                None => return Err(syntax("unterminated string")),
+            }
+        }
+    };
+
+    if chars.next() != Some('{') {
+        return Err(syntax("expected `{`"));
+    }
+    loop {
+        skip_whitespace(&mut chars);
+        if chars.next_if_eq(&'}').is_some() {
+            break;
+        }
+        let name = read_string(&mut chars)?;
+        skip_whitespace(&mut chars);
+        if chars.next() != Some(':') {
+            return Err(syntax("expected `:`"));
+        }
+        skip_whitespace(&mut chars);
+        let value = read_string(&mut chars)?;
+        entries.push((name, value));
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some(',') => {}
+            Some('}') => break,
+            _ => return Err(syntax("expected `,` or `}`")),
+        }
+    }
+    Ok(entries)
+}
+
+/// Reads the hex digits of a `\uXXXX` escape, whose `\u` was already consumed, combining
+/// UTF-16 surrogate pairs.
+fn read_unicode_escape(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Result<char, PaletteParseError> {
+    let invalid = || PaletteParseError::Syntax("invalid `\\u` escape".to_string());
+    let read_hex4 = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        (0..4).try_fold(0_u32, |code, _| {
+            let digit = chars.next().and_then(|c| c.to_digit(16));
+            digit.map(|digit| code * 16 + digit).ok_or_else(invalid)
+        })
+    };
+    let code = read_hex4(chars)?;
+    let code = if (0xd800..0xdc00).contains(&code) {
+        if chars.next() != Some('\\') || chars.next() != Some('u') {
+            return Err(invalid());
+        }
+        let low = read_hex4(chars)?;
+        if !(0xdc00..0xe000).contains(&low) {
+            return Err(invalid());
+        }
+        0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00)
+    } else {
+        code
+    };
+    char::from_u32(code).ok_or_else(invalid)
+}
+
+fn parse_toml(source: &str) -> Result<Vec<(String, String)>, PaletteParseError> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('['))
+        .map(|line| {
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| PaletteParseError::Syntax(format!("expected `=` in `{line}`")))?;
+            let quoted =
+                || PaletteParseError::Syntax(format!("expected a quoted value in `{line}`"));
+            let (value, rest) = value
+                .trim_start()
+                .strip_prefix('"')
+                .and_then(|value| value.split_once('"'))
+                .ok_or_else(quoted)?;
+            // Only a comment may follow the closing quote.
+            let rest = rest.trim_start();
+            if !rest.is_empty() && !rest.starts_with('#') {
+                return Err(quoted());
+            }
+            Ok((name.trim().trim_matches('"').to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// The error returned by [`parse_palette`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteParseError {
+    /// The file is neither a flat JSON object nor flat TOML key/value pairs.
+    Syntax(String),
+    /// An entry whose value could not be parsed as a color.
+    InvalidColor { name: String, value: String },
+}
+
+impl std::fmt::Display for PaletteParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(message) => write!(f, "syntax error: {message}"),
+            Self::InvalidColor { name, value } => {
+                write!(f, "invalid color `{value}` for `{name}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PaletteParseError {}
@@ -0,0 +1,796 @@
+use csscolorparser::Color;
+
+/// A color scale: `(name, hex)` pairs such as `("blue-500", "#3b82f6")`, in the design system's order.
+pub type Scale = &'static [(&'static str, &'static str)];
+
+/// The Tailwind CSS (v3) color scales by name, each with the shades `50` to `950`.
+pub const TAILWIND: &[(&str, Scale)] = &[
+    ("slate", TAILWIND_SLATE),
+    ("gray", TAILWIND_GRAY),
+    ("zinc", TAILWIND_ZINC),
+    ("neutral", TAILWIND_NEUTRAL),
+    ("stone", TAILWIND_STONE),
+    ("red", TAILWIND_RED),
+    ("orange", TAILWIND_ORANGE),
+    ("amber", TAILWIND_AMBER),
+    ("yellow", TAILWIND_YELLOW),
+    ("lime", TAILWIND_LIME),
+    ("green", TAILWIND_GREEN),
+    ("emerald", TAILWIND_EMERALD),
+    ("teal", TAILWIND_TEAL),
+    ("cyan", TAILWIND_CYAN),
+    ("sky", TAILWIND_SKY),
+    ("blue", TAILWIND_BLUE),
+    ("indigo", TAILWIND_INDIGO),
+    ("violet", TAILWIND_VIOLET),
+    ("purple", TAILWIND_PURPLE),
+    ("fuchsia", TAILWIND_FUCHSIA),
+    ("pink", TAILWIND_PINK),
+    ("rose", TAILWIND_ROSE),
+];
+
+/// The Material Design (2014) color scales by name, each with the tones `50` to `900` followed by
+/// the accents `A100` to `A700`. `brown`, `grey` and `blue-grey` have no accents.
+pub const MATERIAL: &[(&str, Scale)] = &[
+    ("red", MATERIAL_RED),
+    ("pink", MATERIAL_PINK),
+    ("purple", MATERIAL_PURPLE),
+    ("deep-purple", MATERIAL_DEEP_PURPLE),
+    ("indigo", MATERIAL_INDIGO),
+    ("blue", MATERIAL_BLUE),
+    ("light-blue", MATERIAL_LIGHT_BLUE),
+    ("cyan", MATERIAL_CYAN),
+    ("teal", MATERIAL_TEAL),
+    ("green", MATERIAL_GREEN),
+    ("light-green", MATERIAL_LIGHT_GREEN),
+    ("lime", MATERIAL_LIME),
+    ("yellow", MATERIAL_YELLOW),
+    ("amber", MATERIAL_AMBER),
+    ("orange", MATERIAL_ORANGE),
+    ("deep-orange", MATERIAL_DEEP_ORANGE),
+    ("brown", MATERIAL_BROWN),
+    ("grey", MATERIAL_GREY),
+    ("blue-grey", MATERIAL_BLUE_GREY),
+];
+
+// Every value is checked while compiling, so parsing them can never fail at runtime.
+const _: () = assert!(all_hex(TAILWIND), "invalid hex color in TAILWIND");
+const _: () = assert!(all_hex(MATERIAL), "invalid hex color in MATERIAL");
+
+const fn all_hex(scales: &[(&str, Scale)]) -> bool {
+    let mut i = 0;
+    while i < scales.len() {
+        let scale = scales[i].1;
+        let mut j = 0;
+        while j < scale.len() {
+            if !is_hex6(scale[j].1) {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn is_hex6(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() != 7 || bytes[0] != b'#' {
+        return false;
+    }
+    let mut i = 1;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_hexdigit() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Looks up a Tailwind scale by name, e.g. `"sky"`.
+pub fn tailwind(name: &str) -> Option<Scale> {
+    find(TAILWIND, name)
+}
+
+/// Looks up a Material scale by name, e.g. `"deep-purple"`.
+pub fn material(name: &str) -> Option<Scale> {
+    find(MATERIAL, name)
+}
+
+fn find(scales: &[(&str, Scale)], name: &str) -> Option<Scale> {
+    scales
+        .iter()
+        .find(|(scale, _)| scale.eq_ignore_ascii_case(name))
+        .map(|(_, scale)| *scale)
+}
+
+/// Parses a scale into named colors, the form returned by [`parse_palette`](crate::palette::parse_palette).
+pub fn named_colors(scale: &[(&str, &str)]) -> Vec<(String, Color)> {
+    scale
+        .iter()
+        .map(|(name, value)| (name.to_string(), parse(value)))
+        .collect()
+}
+
+/// Parses a scale into its colors, e.g. for the `swatches` prop of `ColorPicker`.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::{palettes, Color};
+///
+/// let blues = palettes::colors(palettes::TAILWIND_BLUE);
+/// assert_eq!(blues.len(), 11);
+/// assert_eq!(blues[5], Color::from_rgba8(59, 130, 246, 255));
+/// assert_eq!(palettes::tailwind("blue"), Some(palettes::TAILWIND_BLUE));
+/// ```
+pub fn colors(scale: &[(&str, &str)]) -> Vec<Color> {
+    scale.iter().map(|(_, value)| parse(value)).collect()
+}
+
+fn parse(value: &str) -> Color {
+    value
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid palette color `{value}`"))
+}
+
+/// The Tailwind CSS `slate` scale.
+pub const TAILWIND_SLATE: &[(&str, &str)] = &[
+    ("slate-50", "#f8fafc"),
+    ("slate-100", "#f1f5f9"),
+    ("slate-200", "#e2e8f0"),
+    ("slate-300", "#cbd5e1"),
+    ("slate-400", "#94a3b8"),
+    ("slate-500", "#64748b"),
+    ("slate-600", "#475569"),
+    ("slate-700", "#334155"),
+    ("slate-800", "#1e293b"),
+    ("slate-900", "#0f172a"),
+    ("slate-950", "#020617"),
+];
+
+/// The Tailwind CSS `gray` scale.
+pub const TAILWIND_GRAY: &[(&str, &str)] = &[
+    ("gray-50", "#f9fafb"),
+    ("gray-100", "#f3f4f6"),
+    ("gray-200", "#e5e7eb"),
+    ("gray-300", "#d1d5db"),
+    ("gray-400", "#9ca3af"),
+    ("gray-500", "#6b7280"),
+    ("gray-600", "#4b5563"),
+    ("gray-700", "#374151"),
+    ("gray-800", "#1f2937"),
+    ("gray-900", "#111827"),
+    ("gray-950", "#030712"),
+];
+
+/// The Tailwind CSS `zinc` scale.
+pub const TAILWIND_ZINC: &[(&str, &str)] = &[
+    ("zinc-50", "#fafafa"),
+    ("zinc-100", "#f4f4f5"),
+    ("zinc-200", "#e4e4e7"),
+    ("zinc-300", "#d4d4d8"),
+    ("zinc-400", "#a1a1aa"),
+    ("zinc-500", "#71717a"),
+    ("zinc-600", "#52525b"),
+    ("zinc-700", "#3f3f46"),
+    ("zinc-800", "#27272a"),
+    ("zinc-900", "#18181b"),
+    ("zinc-950", "#09090b"),
+];
+
+/// The Tailwind CSS `neutral` scale.
+pub const TAILWIND_NEUTRAL: &[(&str, &str)] = &[
+    ("neutral-50", "#fafafa"),
+    ("neutral-100", "#f5f5f5"),
+    ("neutral-200", "#e5e5e5"),
+    ("neutral-300", "#d4d4d4"),
+    ("neutral-400", "#a3a3a3"),
+    ("neutral-500", "#737373"),
+    ("neutral-600", "#525252"),
+    ("neutral-700", "#404040"),
+    ("neutral-800", "#262626"),
+    ("neutral-900", "#171717"),
+    ("neutral-950", "#0a0a0a"),
+];
+
+/// The Tailwind CSS `stone` scale.
+pub const TAILWIND_STONE: &[(&str, &str)] = &[
+    ("stone-50", "#fafaf9"),
+    ("stone-100", "#f5f5f4"),
+    ("stone-200", "#e7e5e4"),
+    ("stone-300", "#d6d3d1"),
+    ("stone-400", "#a8a29e"),
+    ("stone-500", "#78716c"),
+    ("stone-600", "#57534e"),
+    ("stone-700", "#44403c"),
+    ("stone-800", "#292524"),
+    ("stone-900", "#1c1917"),
+    ("stone-950", "#0c0a09"),
+];
+
+/// The Tailwind CSS `red` scale.
+pub const TAILWIND_RED: &[(&str, &str)] = &[
+    ("red-50", "#fef2f2"),
+    ("red-100", "#fee2e2"),
+    ("red-200", "#fecaca"),
+    ("red-300", "#fca5a5"),
+    ("red-400", "#f87171"),
+    ("red-500", "#ef4444"),
+    ("red-600", "#dc2626"),
+    ("red-700", "#b91c1c"),
+    ("red-800", "#991b1b"),
+    ("red-900", "#7f1d1d"),
+    ("red-950", "#450a0a"),
+];
+
+/// The Tailwind CSS `orange` scale.
+pub const TAILWIND_ORANGE: &[(&str, &str)] = &[
+    ("orange-50", "#fff7ed"),
+    ("orange-100", "#ffedd5"),
+    ("orange-200", "#fed7aa"),
+    ("orange-300", "#fdba74"),
+    ("orange-400", "#fb923c"),
+    ("orange-500", "#f97316"),
+    ("orange-600", "#ea580c"),
+    ("orange-700", "#c2410c"),
+    ("orange-800", "#9a3412"),
+    ("orange-900", "#7c2d12"),
+    ("orange-950", "#431407"),
+];
+
+/// The Tailwind CSS `amber` scale.
+pub const TAILWIND_AMBER: &[(&str, &str)] = &[
+    ("amber-50", "#fffbeb"),
+    ("amber-100", "#fef3c7"),
+    ("amber-200", "#fde68a"),
+    ("amber-300", "#fcd34d"),
+    ("amber-400", "#fbbf24"),
+    ("amber-500", "#f59e0b"),
+    ("amber-600", "#d97706"),
+    ("amber-700", "#b45309"),
+    ("amber-800", "#92400e"),
+    ("amber-900", "#78350f"),
+    ("amber-950", "#451a03"),
+];
+
+/// The Tailwind CSS `yellow` scale.
+pub const TAILWIND_YELLOW: &[(&str, &str)] = &[
+    ("yellow-50", "#fefce8"),
+    ("yellow-100", "#fef9c3"),
+    ("yellow-200", "#fef08a"),
+    ("yellow-300", "#fde047"),
+    ("yellow-400", "#facc15"),
+    ("yellow-500", "#eab308"),
+    ("yellow-600", "#ca8a04"),
+    ("yellow-700", "#a16207"),
+    ("yellow-800", "#854d0e"),
+    ("yellow-900", "#713f12"),
+    ("yellow-950", "#422006"),
+];
+
+/// The Tailwind CSS `lime` scale.
+pub const TAILWIND_LIME: &[(&str, &str)] = &[
+    ("lime-50", "#f7fee7"),
+    ("lime-100", "#ecfccb"),
+    ("lime-200", "#d9f99d"),
+    ("lime-300", "#bef264"),
+    ("lime-400", "#a3e635"),
+    ("lime-500", "#84cc16"),
+    ("lime-600", "#65a30d"),
+    ("lime-700", "#4d7c0f"),
+    ("lime-800", "#3f6212"),
+    ("lime-900", "#365314"),
+    ("lime-950", "#1a2e05"),
+];
+
+/// The Tailwind CSS `green` scale.
+pub const TAILWIND_GREEN: &[(&str, &str)] = &[
+    ("green-50", "#f0fdf4"),
+    ("green-100", "#dcfce7"),
+    ("green-200", "#bbf7d0"),
+    ("green-300", "#86efac"),
+    ("green-400", "#4ade80"),
+    ("green-500", "#22c55e"),
+    ("green-600", "#16a34a"),
+    ("green-700", "#15803d"),
+    ("green-800", "#166534"),
+    ("green-900", "#14532d"),
+    ("green-950", "#052e16"),
+];
+
+/// The Tailwind CSS `emerald` scale.
+pub const TAILWIND_EMERALD: &[(&str, &str)] = &[
+    ("emerald-50", "#ecfdf5"),
+    ("emerald-100", "#d1fae5"),
+    ("emerald-200", "#a7f3d0"),
+    ("emerald-300", "#6ee7b7"),
+    ("emerald-400", "#34d399"),
+    ("emerald-500", "#10b981"),
+    ("emerald-600", "#059669"),
+    ("emerald-700", "#047857"),
+    ("emerald-800", "#065f46"),
+    ("emerald-900", "#064e3b"),
+    ("emerald-950", "#022c22"),
+];
+
+/// The Tailwind CSS `teal` scale.
+pub const TAILWIND_TEAL: &[(&str, &str)] = &[
+    ("teal-50", "#f0fdfa"),
+    ("teal-100", "#ccfbf1"),
+    ("teal-200", "#99f6e4"),
+    ("teal-300", "#5eead4"),
+    ("teal-400", "#2dd4bf"),
+    ("teal-500", "#14b8a6"),
+    ("teal-600", "#0d9488"),
+    ("teal-700", "#0f766e"),
+    ("teal-800", "#115e59"),
+    ("teal-900", "#134e4a"),
+    ("teal-950", "#042f2e"),
+];
+
+/// The Tailwind CSS `cyan` scale.
+pub const TAILWIND_CYAN: &[(&str, &str)] = &[
+    ("cyan-50", "#ecfeff"),
+    ("cyan-100", "#cffafe"),
+    ("cyan-200", "#a5f3fc"),
+    ("cyan-300", "#67e8f9"),
+    ("cyan-400", "#22d3ee"),
+    ("cyan-500", "#06b6d4"),
+    ("cyan-600", "#0891b2"),
+    ("cyan-700", "#0e7490"),
+    ("cyan-800", "#155e75"),
+    ("cyan-900", "#164e63"),
+    ("cyan-950", "#083344"),
+];
+
+/// The Tailwind CSS `sky` scale.
+pub const TAILWIND_SKY: &[(&str, &str)] = &[
+    ("sky-50", "#f0f9ff"),
+    ("sky-100", "#e0f2fe"),
+    ("sky-200", "#bae6fd"),
+    ("sky-300", "#7dd3fc"),
+    ("sky-400", "#38bdf8"),
+    ("sky-500", "#0ea5e9"),
+    ("sky-600", "#0284c7"),
+    ("sky-700", "#0369a1"),
+    ("sky-800", "#075985"),
+    ("sky-900", "#0c4a6e"),
+    ("sky-950", "#082f49"),
+];
+
+/// The Tailwind CSS `blue` scale.
+pub const TAILWIND_BLUE: &[(&str, &str)] = &[
+    ("blue-50", "#eff6ff"),
+    ("blue-100", "#dbeafe"),
+    ("blue-200", "#bfdbfe"),
+    ("blue-300", "#93c5fd"),
+    ("blue-400", "#60a5fa"),
+    ("blue-500", "#3b82f6"),
+    ("blue-600", "#2563eb"),
+    ("blue-700", "#1d4ed8"),
+    ("blue-800", "#1e40af"),
+    ("blue-900", "#1e3a8a"),
+    ("blue-950", "#172554"),
+];
+
+/// The Tailwind CSS `indigo` scale.
+pub const TAILWIND_INDIGO: &[(&str, &str)] = &[
+    ("indigo-50", "#eef2ff"),
+    ("indigo-100", "#e0e7ff"),
+    ("indigo-200", "#c7d2fe"),
+    ("indigo-300", "#a5b4fc"),
+    ("indigo-400", "#818cf8"),
+    ("indigo-500", "#6366f1"),
+    ("indigo-600", "#4f46e5"),
+    ("indigo-700", "#4338ca"),
+    ("indigo-800", "#3730a3"),
+    ("indigo-900", "#312e81"),
+    ("indigo-950", "#1e1b4b"),
+];
+
+/// The Tailwind CSS `violet` scale.
+pub const TAILWIND_VIOLET: &[(&str, &str)] = &[
+    ("violet-50", "#f5f3ff"),
+    ("violet-100", "#ede9fe"),
+    ("violet-200", "#ddd6fe"),
+    ("violet-300", "#c4b5fd"),
+    ("violet-400", "#a78bfa"),
+    ("violet-500", "#8b5cf6"),
+    ("violet-600", "#7c3aed"),
+    ("violet-700", "#6d28d9"),
+    ("violet-800", "#5b21b6"),
+    ("violet-900", "#4c1d95"),
+    ("violet-950", "#2e1065"),
+];
+
+/// The Tailwind CSS `purple` scale.
+pub const TAILWIND_PURPLE: &[(&str, &str)] = &[
+    ("purple-50", "#faf5ff"),
+    ("purple-100", "#f3e8ff"),
+    ("purple-200", "#e9d5ff"),
+    ("purple-300", "#d8b4fe"),
+    ("purple-400", "#c084fc"),
+    ("purple-500", "#a855f7"),
+    ("purple-600", "#9333ea"),
+    ("purple-700", "#7e22ce"),
+    ("purple-800", "#6b21a8"),
+    ("purple-900", "#581c87"),
+    ("purple-950", "#3b0764"),
+];
+
+/// The Tailwind CSS `fuchsia` scale.
+pub const TAILWIND_FUCHSIA: &[(&str, &str)] = &[
+    ("fuchsia-50", "#fdf4ff"),
+    ("fuchsia-100", "#fae8ff"),
+    ("fuchsia-200", "#f5d0fe"),
+    ("fuchsia-300", "#f0abfc"),
+    ("fuchsia-400", "#e879f9"),
+    ("fuchsia-500", "#d946ef"),
+    ("fuchsia-600", "#c026d3"),
+    ("fuchsia-700", "#a21caf"),
+    ("fuchsia-800", "#86198f"),
+    ("fuchsia-900", "#701a75"),
+    ("fuchsia-950", "#4a044e"),
+];
+
+/// The Tailwind CSS `pink` scale.
+pub const TAILWIND_PINK: &[(&str, &str)] = &[
+    ("pink-50", "#fdf2f8"),
+    ("pink-100", "#fce7f3"),
+    ("pink-200", "#fbcfe8"),
+    ("pink-300", "#f9a8d4"),
+    ("pink-400", "#f472b6"),
+    ("pink-500", "#ec4899"),
+    ("pink-600", "#db2777"),
+    ("pink-700", "#be185d"),
+    ("pink-800", "#9d174d"),
+    ("pink-900", "#831843"),
+    ("pink-950", "#500724"),
+];
+
+/// The Tailwind CSS `rose` scale.
+pub const TAILWIND_ROSE: &[(&str, &str)] = &[
+    ("rose-50", "#fff1f2"),
+    ("rose-100", "#ffe4e6"),
+    ("rose-200", "#fecdd3"),
+    ("rose-300", "#fda4af"),
+    ("rose-400", "#fb7185"),
+    ("rose-500", "#f43f5e"),
+    ("rose-600", "#e11d48"),
+    ("rose-700", "#be123c"),
+    ("rose-800", "#9f1239"),
+    ("rose-900", "#881337"),
+    ("rose-950", "#4c0519"),
+];
+
+/// The Material Design `red` scale.
+pub const MATERIAL_RED: &[(&str, &str)] = &[
+    ("red-50", "#ffebee"),
+    ("red-100", "#ffcdd2"),
+    ("red-200", "#ef9a9a"),
+    ("red-300", "#e57373"),
+    ("red-400", "#ef5350"),
+    ("red-500", "#f44336"),
+    ("red-600", "#e53935"),
+    ("red-700", "#d32f2f"),
+    ("red-800", "#c62828"),
+    ("red-900", "#b71c1c"),
+    ("red-A100", "#ff8a80"),
+    ("red-A200", "#ff5252"),
+    ("red-A400", "#ff1744"),
+    ("red-A700", "#d50000"),
+];
+
+/// The Material Design `pink` scale.
+pub const MATERIAL_PINK: &[(&str, &str)] = &[
+    ("pink-50", "#fce4ec"),
+    ("pink-100", "#f8bbd0"),
+    ("pink-200", "#f48fb1"),
+    ("pink-300", "#f06292"),
+    ("pink-400", "#ec407a"),
+    ("pink-500", "#e91e63"),
+    ("pink-600", "#d81b60"),
+    ("pink-700", "#c2185b"),
+    ("pink-800", "#ad1457"),
+    ("pink-900", "#880e4f"),
+    ("pink-A100", "#ff80ab"),
+    ("pink-A200", "#ff4081"),
+    ("pink-A400", "#f50057"),
+    ("pink-A700", "#c51162"),
+];
+
+/// The Material Design `purple` scale.
+pub const MATERIAL_PURPLE: &[(&str, &str)] = &[
+    ("purple-50", "#f3e5f5"),
+    ("purple-100", "#e1bee7"),
+    ("purple-200", "#ce93d8"),
+    ("purple-300", "#ba68c8"),
+    ("purple-400", "#ab47bc"),
+    ("purple-500", "#9c27b0"),
+    ("purple-600", "#8e24aa"),
+    ("purple-700", "#7b1fa2"),
+    ("purple-800", "#6a1b9a"),
+    ("purple-900", "#4a148c"),
+    ("purple-A100", "#ea80fc"),
+    ("purple-A200", "#e040fb"),
+    ("purple-A400", "#d500f9"),
+    ("purple-A700", "#aa00ff"),
+];
+
+/// The Material Design `deep-purple` scale.
+pub const MATERIAL_DEEP_PURPLE: &[(&str, &str)] = &[
+    ("deep-purple-50", "#ede7f6"),
+    ("deep-purple-100", "#d1c4e9"),
+    ("deep-purple-200", "#b39ddb"),
+    ("deep-purple-300", "#9575cd"),
+    ("deep-purple-400", "#7e57c2"),
+    ("deep-purple-500", "#673ab7"),
+    ("deep-purple-600", "#5e35b1"),
+    ("deep-purple-700", "#512da8"),
+    ("deep-purple-800", "#4527a0"),
+    ("deep-purple-900", "#311b92"),
+    ("deep-purple-A100", "#b388ff"),
+    ("deep-purple-A200", "#7c4dff"),
+    ("deep-purple-A400", "#651fff"),
+    ("deep-purple-A700", "#6200ea"),
+];
+
+/// The Material Design `indigo` scale.
+pub const MATERIAL_INDIGO: &[(&str, &str)] = &[
+    ("indigo-50", "#e8eaf6"),
+    ("indigo-100", "#c5cae9"),
+    ("indigo-200", "#9fa8da"),
+    ("indigo-300", "#7986cb"),
+    ("indigo-400", "#5c6bc0"),
+    ("indigo-500", "#3f51b5"),
+    ("indigo-600", "#3949ab"),
+    ("indigo-700", "#303f9f"),
+    ("indigo-800", "#283593"),
+    ("indigo-900", "#1a237e"),
+    ("indigo-A100", "#8c9eff"),
+    ("indigo-A200", "#536dfe"),
+    ("indigo-A400", "#3d5afe"),
+    ("indigo-A700", "#304ffe"),
+];
+
+/// The Material Design `blue` scale.
+pub const MATERIAL_BLUE: &[(&str, &str)] = &[
+    ("blue-50", "#e3f2fd"),
+    ("blue-100", "#bbdefb"),
+    ("blue-200", "#90caf9"),
+    ("blue-300", "#64b5f6"),
+    ("blue-400", "#42a5f5"),
+    ("blue-500", "#2196f3"),
+    ("blue-600", "#1e88e5"),
+    ("blue-700", "#1976d2"),
+    ("blue-800", "#1565c0"),
+    ("blue-900", "#0d47a1"),
+    ("blue-A100", "#82b1ff"),
+    ("blue-A200", "#448aff"),
+    ("blue-A400", "#2979ff"),
+    ("blue-A700", "#2962ff"),
+];
+
+/// The Material Design `light-blue` scale.
+pub const MATERIAL_LIGHT_BLUE: &[(&str, &str)] = &[
+    ("light-blue-50", "#e1f5fe"),
+    ("light-blue-100", "#b3e5fc"),
+    ("light-blue-200", "#81d4fa"),
+    ("light-blue-300", "#4fc3f7"),
+    ("light-blue-400", "#29b6f6"),
+    ("light-blue-500", "#03a9f4"),
+    ("light-blue-600", "#039be5"),
+    ("light-blue-700", "#0288d1"),
+    ("light-blue-800", "#0277bd"),
+    ("light-blue-900", "#01579b"),
+    ("light-blue-A100", "#80d8ff"),
+    ("light-blue-A200", "#40c4ff"),
+    ("light-blue-A400", "#00b0ff"),
+    ("light-blue-A700", "#0091ea"),
+];
+
+/// The Material Design `cyan` scale.
+pub const MATERIAL_CYAN: &[(&str, &str)] = &[
+    ("cyan-50", "#e0f7fa"),
+    ("cyan-100", "#b2ebf2"),
+    ("cyan-200", "#80deea"),
+    ("cyan-300", "#4dd0e1"),
+    ("cyan-400", "#26c6da"),
+    ("cyan-500", "#00bcd4"),
+    ("cyan-600", "#00acc1"),
+    ("cyan-700", "#0097a7"),
+    ("cyan-800", "#00838f"),
+    ("cyan-900", "#006064"),
+    ("cyan-A100", "#84ffff"),
+    ("cyan-A200", "#18ffff"),
+    ("cyan-A400", "#00e5ff"),
+    ("cyan-A700", "#00b8d4"),
+];
+
+/// The Material Design `teal` scale.
+pub const MATERIAL_TEAL: &[(&str, &str)] = &[
+    ("teal-50", "#e0f2f1"),
+    ("teal-100", "#b2dfdb"),
+    ("teal-200", "#80cbc4"),
+    ("teal-300", "#4db6ac"),
+    ("teal-400", "#26a69a"),
+    ("teal-500", "#009688"),
+    ("teal-600", "#00897b"),
+    ("teal-700", "#00796b"),
+    ("teal-800", "#00695c"),
+    ("teal-900", "#004d40"),
+    ("teal-A100", "#a7ffeb"),
+    ("teal-A200", "#64ffda"),
+    ("teal-A400", "#1de9b6"),
+    ("teal-A700", "#00bfa5"),
+];
+
+/// The Material Design `green` scale.
+pub const MATERIAL_GREEN: &[(&str, &str)] = &[
+    ("green-50", "#e8f5e9"),
+    ("green-100", "#c8e6c9"),
+    ("green-200", "#a5d6a7"),
+    ("green-300", "#81c784"),
+    ("green-400", "#66bb6a"),
+    ("green-500", "#4caf50"),
+    ("green-600", "#43a047"),
+    ("green-700", "#388e3c"),
+    ("green-800", "#2e7d32"),
+    ("green-900", "#1b5e20"),
+    ("green-A100", "#b9f6ca"),
+    ("green-A200", "#69f0ae"),
+    ("green-A400", "#00e676"),
+    ("green-A700", "#00c853"),
+];
+
+/// The Material Design `light-green` scale.
+pub const MATERIAL_LIGHT_GREEN: &[(&str, &str)] = &[
+    ("light-green-50", "#f1f8e9"),
+    ("light-green-100", "#dcedc8"),
+    ("light-green-200", "#c5e1a5"),
+    ("light-green-300", "#aed581"),
+    ("light-green-400", "#9ccc65"),
+    ("light-green-500", "#8bc34a"),
+    ("light-green-600", "#7cb342"),
+    ("light-green-700", "#689f38"),
+    ("light-green-800", "#558b2f"),
+    ("light-green-900", "#33691e"),
+    ("light-green-A100", "#ccff90"),
+    ("light-green-A200", "#b2ff59"),
+    ("light-green-A400", "#76ff03"),
+    ("light-green-A700", "#64dd17"),
+];
+
+/// The Material Design `lime` scale.
+pub const MATERIAL_LIME: &[(&str, &str)] = &[
+    ("lime-50", "#f9fbe7"),
+    ("lime-100", "#f0f4c3"),
+    ("lime-200", "#e6ee9c"),
+    ("lime-300", "#dce775"),
+    ("lime-400", "#d4e157"),
+    ("lime-500", "#cddc39"),
+    ("lime-600", "#c0ca33"),
+    ("lime-700", "#afb42b"),
+    ("lime-800", "#9e9d24"),
+    ("lime-900", "#827717"),
+    ("lime-A100", "#f4ff81"),
+    ("lime-A200", "#eeff41"),
+    ("lime-A400", "#c6ff00"),
+    ("lime-A700", "#aeea00"),
+];
+
+/// The Material Design `yellow` scale.
+pub const MATERIAL_YELLOW: &[(&str, &str)] = &[
+    ("yellow-50", "#fffde7"),
+    ("yellow-100", "#fff9c4"),
+    ("yellow-200", "#fff59d"),
+    ("yellow-300", "#fff176"),
+    ("yellow-400", "#ffee58"),
+    ("yellow-500", "#ffeb3b"),
+    ("yellow-600", "#fdd835"),
+    ("yellow-700", "#fbc02d"),
+    ("yellow-800", "#f9a825"),
+    ("yellow-900", "#f57f17"),
+    ("yellow-A100", "#ffff8d"),
+    ("yellow-A200", "#ffff00"),
+    ("yellow-A400", "#ffea00"),
+    ("yellow-A700", "#ffd600"),
+];
+
+/// The Material Design `amber` scale.
+pub const MATERIAL_AMBER: &[(&str, &str)] = &[
+    ("amber-50", "#fff8e1"),
+    ("amber-100", "#ffecb3"),
+    ("amber-200", "#ffe082"),
+    ("amber-300", "#ffd54f"),
+    ("amber-400", "#ffca28"),
+    ("amber-500", "#ffc107"),
+    ("amber-600", "#ffb300"),
+    ("amber-700", "#ffa000"),
+    ("amber-800", "#ff8f00"),
+    ("amber-900", "#ff6f00"),
+    ("amber-A100", "#ffe57f"),
+    ("amber-A200", "#ffd740"),
+    ("amber-A400", "#ffc400"),
+    ("amber-A700", "#ffab00"),
+];
+
+/// The Material Design `orange` scale.
+pub const MATERIAL_ORANGE: &[(&str, &str)] = &[
+    ("orange-50", "#fff3e0"),
+    ("orange-100", "#ffe0b2"),
+    ("orange-200", "#ffcc80"),
+    ("orange-300", "#ffb74d"),
+    ("orange-400", "#ffa726"),
+    ("orange-500", "#ff9800"),
+    ("orange-600", "#fb8c00"),
+    ("orange-700", "#f57c00"),
+    ("orange-800", "#ef6c00"),
+    ("orange-900", "#e65100"),
+    ("orange-A100", "#ffd180"),
+    ("orange-A200", "#ffab40"),
+    ("orange-A400", "#ff9100"),
+    ("orange-A700", "#ff6d00"),
+];
+
+/// The Material Design `deep-orange` scale.
+pub const MATERIAL_DEEP_ORANGE: &[(&str, &str)] = &[
+    ("deep-orange-50", "#fbe9e7"),
+    ("deep-orange-100", "#ffccbc"),
+    ("deep-orange-200", "#ffab91"),
+    ("deep-orange-300", "#ff8a65"),
+    ("deep-orange-400", "#ff7043"),
+    ("deep-orange-500", "#ff5722"),
+    ("deep-orange-600", "#f4511e"),
+    ("deep-orange-700", "#e64a19"),
+    ("deep-orange-800", "#d84315"),
+    ("deep-orange-900", "#bf360c"),
+    ("deep-orange-A100", "#ff9e80"),
+    ("deep-orange-A200", "#ff6e40"),
+    ("deep-orange-A400", "#ff3d00"),
+    ("deep-orange-A700", "#dd2c00"),
+];
+
+/// The Material Design `brown` scale.
+pub const MATERIAL_BROWN: &[(&str, &str)] = &[
+    ("brown-50", "#efebe9"),
+    ("brown-100", "#d7ccc8"),
+    ("brown-200", "#bcaaa4"),
+    ("brown-300", "#a1887f"),
+    ("brown-400", "#8d6e63"),
+    ("brown-500", "#795548"),
+    ("brown-600", "#6d4c41"),
+    ("brown-700", "#5d4037"),
+    ("brown-800", "#4e342e"),
+    ("brown-900", "#3e2723"),
+];
+
+/// The Material Design `grey` scale.
+pub const MATERIAL_GREY: &[(&str, &str)] = &[
+    ("grey-50", "#fafafa"),
+    ("grey-100", "#f5f5f5"),
+    ("grey-200", "#eeeeee"),
+    ("grey-300", "#e0e0e0"),
+    ("grey-400", "#bdbdbd"),
+    ("grey-500", "#9e9e9e"),
+    ("grey-600", "#757575"),
+    ("grey-700", "#616161"),
+    ("grey-800", "#424242"),
+    ("grey-900", "#212121"),
+];
+
+/// The Material Design `blue-grey` scale.
+pub const MATERIAL_BLUE_GREY: &[(&str, &str)] = &[
+    ("blue-grey-50", "#eceff1"),
+    ("blue-grey-100", "#cfd8dc"),
+    ("blue-grey-200", "#b0bec5"),
+    ("blue-grey-300", "#90a4ae"),
+    ("blue-grey-400", "#78909c"),
+    ("blue-grey-500", "#607d8b"),
+    ("blue-grey-600", "#546e7a"),
+    ("blue-grey-700", "#455a64"),
+    ("blue-grey-800", "#37474f"),
+    ("blue-grey-900", "#263238"),
+];
@@ -0,0 +1,46 @@
+//! Convenient re-exports and constructors for working with colors.
+//!
+//! ```
+//! use leptos_color::prelude::*;
+//!
+//! let red = rgb8(255, 0, 0);
+//! assert_eq!(hex("#ff0000"), Some(red));
+//! assert_eq!(rgba8(255, 0, 0, 255), red);
+//! ```
+
+pub use crate::color_ext::ColorExt;
+pub use crate::components::color_picker::{
+    AutoFocusTarget, ChangeSource, ColorModel, ColorPicker, InputsPosition, InteractionEvent,
+    PickerLayout,
+};
+pub use crate::components::hsv_color_picker::HsvColorPicker;
+pub use crate::components::swatches::Swatches;
+#[cfg(feature = "color_input")]
+pub use crate::components::{
+    color_input::{ColorInput, OpenChange, OpenReason},
+    color_swatch_button::{ColorSwatchButton, SwatchShape},
+};
+pub use crate::format::{format_color, parse_color, ColorFormat};
+pub use crate::theme::{Theme, ThemeProvider};
+pub use crate::Color;
+
+/// Creates an opaque [`Color`] from 8-bit red, green and blue channels.
+pub fn rgb8(r: u8, g: u8, b: u8) -> Color {
+    Color::from_rgba8(r, g, b, 255)
+}
+
+/// Creates a [`Color`] from 8-bit red, green, blue and alpha channels.
+pub fn rgba8(r: u8, g: u8, b: u8, a: u8) -> Color {
+    Color::from_rgba8(r, g, b, a)
+}
+
+/// Parses a hexadecimal color such as `#f80`, `#ff8800` or `ff8800cc`.
+///
+/// Returns `None` if the value is not a valid hex color.
+pub fn hex(value: &str) -> Option<Color> {
+    let digits = value.trim().trim_start_matches('#');
+    if !matches!(digits.len(), 3 | 4 | 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    format!("#{digits}").parse().ok()
+}
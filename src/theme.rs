@@ -1,8 +1,10 @@
 use csscolorparser::Color;
+use leptos::prelude::*;
 /// A struct representing the theme configuration for color picker components.
 ///
 /// `Theme` provides a set of customizable properties to control the appearance
 /// of color picker components, including colors, dimensions, and style attributes.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Theme {
     /// The background color of the color picker.
     background: Color,
@@ -18,6 +20,10 @@ pub struct Theme {
     boxShadow: String,
     /// The width of the color picker container.
     width: String,
+    /// The border color of inputs holding an invalid value.
+    invalidBorder: Color,
+    /// The background color of inputs holding an invalid value.
+    invalidBackground: Color,
 }
 
 impl Theme {
@@ -45,6 +51,8 @@ impl Theme {
             borderRadius: "4px".to_string(),
             boxShadow: "0px 8px 16px rgba(0, 0, 0, 0.1)".to_string(),
             width: "280px".to_string(),
+            invalidBorder: "#dc2626".parse::<Color>().unwrap(),
+            invalidBackground: "#fef2f2".parse::<Color>().unwrap(),
         }
     }
     /// Creates a new `Theme` instance with dark theme settings.
@@ -62,6 +70,8 @@ impl Theme {
             borderRadius: "4px".to_string(),
             boxShadow: "0px 8px 16px rgba(0, 0, 0, 0.1)".to_string(),
             width: "280px".to_string(),
+            invalidBorder: "#f87171".parse::<Color>().unwrap(),
+            invalidBackground: "#3f1d1d".parse::<Color>().unwrap(),
         }
     }
     /// Creates a new `Theme` instance with custom settings.
@@ -76,6 +86,9 @@ impl Theme {
     /// * `box_shadow` - The box shadow as a CSS-compatible string.
     /// * `width` - The width of the color picker as a CSS-compatible string.
     ///
+    /// The invalid-input colors are taken from [`Theme::light`]; use
+    /// [`Theme::invalid_border`] and [`Theme::invalid_background`] to change them.
+    ///
     /// # Example
     /// ```
     /// use csscolorparser::Color;
@@ -107,6 +120,7 @@ impl Theme {
             borderRadius: border_radius,
             boxShadow: box_shadow,
             width,
+            ..Self::light()
         }
     }
 
@@ -145,6 +159,16 @@ impl Theme {
         self
     }
 
+    pub fn invalid_border(&mut self, invalid_border: Color) -> &mut Self {
+        self.invalidBorder = invalid_border;
+        self
+    }
+
+    pub fn invalid_background(&mut self, invalid_background: Color) -> &mut Self {
+        self.invalidBackground = invalid_background;
+        self
+    }
+
     /// Converts the theme settings to a CSS-compatible string.
     ///
     /// This method generates CSS custom properties (variables) that can be
@@ -164,14 +188,240 @@ impl Theme {
                  --lpc-border-color: {}; \
                  --lpc-border-radius: {}; \
                  --lpc-box-shadow: {}; \
-                 --lpc-width: {};",
+                 --lpc-width: {}; \
+                 --lpc-invalid-border: {}; \
+                 --lpc-invalid-bg: {};",
             self.background.to_hex_string(),
             self.inputBackground.to_hex_string(),
             self.color.to_hex_string(),
             self.borderColor.to_hex_string(),
             self.borderRadius,
             self.boxShadow,
-            self.width
+            self.width,
+            self.invalidBorder.to_hex_string(),
+            self.invalidBackground.to_hex_string()
         )
     }
+
+    /// Parses a string of `--lpc-*` declarations, as produced by [`Theme::to_style`],
+    /// back into a `Theme`.
+    ///
+    /// Colors are parsed with `csscolorparser`, all other values are kept verbatim.
+    /// Declarations of other properties are ignored. The invalid-input colors are optional
+    /// and default to those of [`Theme::light`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThemeParseError`] if a declaration is malformed, a color cannot be
+    /// parsed, or one of the theme properties is missing.
+    ///
+    /// # Example
+    /// ```
+    /// use leptos_color::theme::Theme;
+    ///
+    /// let css = Theme::light().to_style();
+    /// let theme = Theme::from_style(&css).unwrap();
+    /// assert_eq!(theme.to_style(), css);
+    /// ```
+    pub fn from_style(css: &str) -> Result<Self, ThemeParseError> {
+        let mut background = None;
+        let mut input_background = None;
+        let mut color = None;
+        let mut border_color = None;
+        let mut border_radius = None;
+        let mut box_shadow = None;
+        let mut width = None;
+        let mut invalid_border = None;
+        let mut invalid_background = None;
+
+        for declaration in css.split(';').map(str::trim).filter(|d| !d.is_empty()) {
+            let (property, value) = declaration
+                .split_once(':')
+                .ok_or_else(|| ThemeParseError::MalformedDeclaration(declaration.to_string()))?;
+            let (property, value) = (property.trim(), value.trim());
+            let parse_color = |value: &str| {
+                value
+                    .parse::<Color>()
+                    .map_err(|_| ThemeParseError::InvalidColor {
+                        property: property.to_string(),
+                        value: value.to_string(),
+                    })
+            };
+            match property {
+                "--lpc-background" => background = Some(parse_color(value)?),
+                "--lpc-input-background" => input_background = Some(parse_color(value)?),
+                "--lpc-color" => color = Some(parse_color(value)?),
+                "--lpc-border-color" => border_color = Some(parse_color(value)?),
+                "--lpc-border-radius" => border_radius = Some(value.to_string()),
+                "--lpc-box-shadow" => box_shadow = Some(value.to_string()),
+                "--lpc-width" => width = Some(value.to_string()),
+                "--lpc-invalid-border" => invalid_border = Some(parse_color(value)?),
+                "--lpc-invalid-bg" => invalid_background = Some(parse_color(value)?),
+                _ => {}
+            }
+        }
+
+        let mut theme = Self::custom(
+            background.ok_or(ThemeParseError::MissingProperty("--lpc-background"))?,
+            input_background.ok_or(ThemeParseError::MissingProperty("--lpc-input-background"))?,
+            color.ok_or(ThemeParseError::MissingProperty("--lpc-color"))?,
+            border_color.ok_or(ThemeParseError::MissingProperty("--lpc-border-color"))?,
+            border_radius.ok_or(ThemeParseError::MissingProperty("--lpc-border-radius"))?,
+            box_shadow.ok_or(ThemeParseError::MissingProperty("--lpc-box-shadow"))?,
+            width.ok_or(ThemeParseError::MissingProperty("--lpc-width"))?,
+        );
+        if let Some(invalid_border) = invalid_border {
+            theme.invalid_border(invalid_border);
+        }
+        if let Some(invalid_background) = invalid_background {
+            theme.invalid_background(invalid_background);
+        }
+        Ok(theme)
+    }
+}
+
+/// A plain, owned representation of a [`Theme`] for persisting it.
+///
+/// Colors are stored as hex strings (`#rrggbb`, or `#rrggbbaa` when translucent) and all other
+/// values as their CSS text, so the struct can be written out with any serialization mechanism
+/// and turned back into a theme with `Theme::try_from`. Unlike `Theme`'s internals, its fields are
+/// public and stable.
+///
+/// # Example
+///
+/// ```
+/// use leptos_color::theme::{Theme, ThemeData};
+///
+/// let data = ThemeData::from(&Theme::dark());
+/// assert_eq!(data.color, "#e3e3e3");
+/// let theme = Theme::try_from(data).unwrap();
+/// assert_eq!(theme.to_style(), Theme::dark().to_style());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeData {
+    pub background: String,
+    pub input_background: String,
+    pub color: String,
+    pub border_color: String,
+    pub border_radius: String,
+    pub box_shadow: String,
+    pub width: String,
+    pub invalid_border: String,
+    pub invalid_background: String,
+}
+
+impl From<&Theme> for ThemeData {
+    fn from(theme: &Theme) -> Self {
+        Self {
+            background: theme.background.to_hex_string(),
+            input_background: theme.inputBackground.to_hex_string(),
+            color: theme.color.to_hex_string(),
+            border_color: theme.borderColor.to_hex_string(),
+            border_radius: theme.borderRadius.clone(),
+            box_shadow: theme.boxShadow.clone(),
+            width: theme.width.clone(),
+            invalid_border: theme.invalidBorder.to_hex_string(),
+            invalid_background: theme.invalidBackground.to_hex_string(),
+        }
+    }
+}
+
+impl TryFrom<ThemeData> for Theme {
+    type Error = ThemeParseError;
+
+    /// Converts persisted [`ThemeData`] back into a theme.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeParseError::InvalidColor`], naming the field, if a color cannot be parsed.
+    fn try_from(data: ThemeData) -> Result<Self, Self::Error> {
+        let parse_color = |property: &str, value: &str| {
+            value
+                .parse::<Color>()
+                .map_err(|_| ThemeParseError::InvalidColor {
+                    property: property.to_string(),
+                    value: value.to_string(),
+                })
+        };
+        let mut theme = Self::custom(
+            parse_color("background", &data.background)?,
+            parse_color("input_background", &data.input_background)?,
+            parse_color("color", &data.color)?,
+            parse_color("border_color", &data.border_color)?,
+            data.border_radius,
+            data.box_shadow,
+            data.width,
+        );
+        theme
+            .invalid_border(parse_color("invalid_border", &data.invalid_border)?)
+            .invalid_background(parse_color("invalid_background", &data.invalid_background)?);
+        Ok(theme)
+    }
+}
+
+/// The error returned by [`Theme::from_style`] and by converting [`ThemeData`] into a [`Theme`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeParseError {
+    /// A declaration without a `:` separating property and value.
+    MalformedDeclaration(String),
+    /// A color property whose value could not be parsed.
+    InvalidColor { property: String, value: String },
+    /// A theme property that was not declared.
+    MissingProperty(&'static str),
+}
+
+impl std::fmt::Display for ThemeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MalformedDeclaration(declaration) => {
+                write!(f, "malformed declaration `{declaration}`")
+            }
+            Self::InvalidColor { property, value } => {
+                write!(f, "invalid color `{value}` for `{property}`")
+            }
+            Self::MissingProperty(property) => write!(f, "missing property `{property}`"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeParseError {}
+
+#[derive(Clone, Copy)]
+struct ThemeContext(Signal<Theme>);
+
+/// Provides a [`Theme`] to every color picker component rendered inside it.
+///
+/// `ColorPicker` and `ColorInput` read the theme from this context when no explicit
+/// `theme` prop is given. An explicit `theme` prop always takes precedence.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_color::{components::color_picker::ColorPicker, theme::{Theme, ThemeProvider}, Color};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let color = RwSignal::new(Color::new(1.0, 1.0, 1.0, 1.0));
+///
+///     view! {
+///         <ThemeProvider theme=Theme::dark()>
+///             <ColorPicker color=color on_change=move |c| color.set(c) />
+///             <ColorPicker color=color on_change=move |c| color.set(c) />
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeProvider(#[prop(into)] theme: Signal<Theme>, children: Children) -> impl IntoView {
+    provide_context(ThemeContext(theme));
+    children()
+}
+
+/// Resolves the theme for a component: the explicit prop, then the nearest
+/// [`ThemeProvider`], then [`Theme::default`].
+pub(crate) fn use_theme(theme: Option<Signal<Theme>>) -> Signal<Theme> {
+    theme
+        .or_else(|| use_context::<ThemeContext>().map(|context| context.0))
+        .unwrap_or_else(|| Theme::default().into())
 }
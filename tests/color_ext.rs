@@ -0,0 +1,23 @@
+use leptos_color::{
+    color_ext::{channel_from_255, channel_to_u8},
+    Color,
+};
+
+#[test]
+fn every_byte_round_trips_through_the_inputs() {
+    for byte in 0..=255u8 {
+        let value = channel_from_255(byte as f32);
+        assert_eq!(channel_to_u8(value), byte);
+
+        let color = Color::new(value, value, value, value);
+        assert_eq!(color.to_rgba8(), [byte; 4], "byte {byte}");
+    }
+}
+
+#[test]
+fn out_of_range_channels_are_clamped() {
+    assert_eq!(channel_from_255(-10.0), 0.0);
+    assert_eq!(channel_from_255(300.0), 1.0);
+    assert_eq!(channel_to_u8(1.5), 255);
+    assert_eq!(channel_to_u8(-0.5), 0);
+}
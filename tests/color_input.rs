@@ -0,0 +1,146 @@
+//! Browser tests for `ColorInput`.
+//!
+//! Run with `wasm-pack test --headless --firefox --features csr`.
+#![cfg(all(target_arch = "wasm32", feature = "color_input"))]
+
+mod common;
+
+use common::commit_input;
+use leptos::prelude::*;
+use leptos::task::Executor;
+use leptos_color::{
+    components::color_input::{ColorInput, OpenChange, OpenReason},
+    Color,
+};
+use wasm_bindgen_test::*;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, HtmlInputElement, KeyboardEvent, KeyboardEventInit};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn reconciles_with_the_rendered_value_attribute() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let parent = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    document().body().unwrap().append_child(&parent).unwrap();
+    leptos::mount::mount_to(parent.clone(), move || {
+        view! { <ColorInput color=color on_change=move |c| color.set(c) /> }
+    })
+    .forget();
+
+    // Effects have not run yet, so this stands in for markup rendered on the server
+    // with a different value than the client's initial signal.
+    let input = parent.query_selector("input").unwrap().unwrap();
+    input.set_attribute("value", "rgba(0, 0, 255, 1)").unwrap();
+    Executor::tick().await;
+
+    assert_eq!(color.get_untracked().to_rgba8(), [0, 0, 255, 255]);
+}
+
+#[wasm_bindgen_test]
+async fn matching_rendered_value_does_not_emit() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(false);
+    let parent = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    document().body().unwrap().append_child(&parent).unwrap();
+    leptos::mount::mount_to(parent.clone(), move || {
+        view! { <ColorInput color=color on_change=move |_| emitted.set(true) /> }
+    })
+    .forget();
+    Executor::tick().await;
+
+    assert!(!emitted.get_untracked());
+}
+
+#[wasm_bindgen_test]
+async fn reports_the_reason_for_open_changes() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let open = RwSignal::new(false);
+    let changes = RwSignal::new(Vec::<OpenChange>::new());
+    let parent = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    document().body().unwrap().append_child(&parent).unwrap();
+    leptos::mount::mount_to(parent.clone(), move || {
+        view! {
+            <ColorInput
+                color=color
+                open=open
+                on_open_change=move |change| changes.update(|changes| changes.push(change))
+                on_change=move |c| color.set(c)
+            />
+        }
+    })
+    .forget();
+    Executor::tick().await;
+
+    let input = parent
+        .query_selector("input")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    input.click();
+    Executor::tick().await;
+    let init = KeyboardEventInit::new();
+    init.set_key("Escape");
+    let escape = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    input.dispatch_event(&escape).unwrap();
+    Executor::tick().await;
+    open.set(true);
+    Executor::tick().await;
+
+    assert_eq!(
+        changes.get_untracked(),
+        vec![
+            OpenChange {
+                open: true,
+                reason: OpenReason::TriggerClick,
+            },
+            OpenChange {
+                open: false,
+                reason: OpenReason::Escape,
+            },
+            OpenChange {
+                open: true,
+                reason: OpenReason::Programmatic,
+            },
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn force_opaque_drops_the_alpha_of_typed_colors() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    document().body().unwrap().append_child(&parent).unwrap();
+    leptos::mount::mount_to(parent.clone(), move || {
+        view! {
+            <ColorInput color=color force_opaque=true on_change=move |c| emitted.set(Some(c)) />
+        }
+    })
+    .forget();
+    Executor::tick().await;
+
+    let input = parent
+        .query_selector("input")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlInputElement>();
+    commit_input(&input, "#0000ff80");
+    Executor::tick().await;
+
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    assert_eq!(emitted.to_rgba8(), [0, 0, 255, 255]);
+    assert_eq!(emitted.a, 1.0);
+}
@@ -0,0 +1,511 @@
+//! Browser tests for `ColorPicker`.
+//!
+//! Run with `wasm-pack test --headless --firefox --features csr`.
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use common::commit_input;
+use leptos::prelude::*;
+use leptos::task::Executor;
+use leptos_color::{
+    components::color_picker::{ColorPicker, PickerLayout},
+    theme::Theme,
+    Color,
+};
+use wasm_bindgen_test::*;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{
+    HtmlElement, HtmlInputElement, KeyboardEvent, KeyboardEventInit, MouseEvent, MouseEventInit,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Mounts `view`, typically a `ColorPicker`, into a new element appended to the body.
+fn mount_picker<N: IntoView + 'static>(view: impl FnOnce() -> N + 'static) -> HtmlElement {
+    let parent = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    document().body().unwrap().append_child(&parent).unwrap();
+    leptos::mount::mount_to(parent.clone(), view).forget();
+    parent
+}
+
+fn query<T: JsCast>(parent: &HtmlElement, selector: &str) -> T {
+    parent
+        .query_selector(selector)
+        .unwrap()
+        .unwrap_or_else(|| panic!("no element matches {selector}"))
+        .unchecked_into::<T>()
+}
+
+#[wasm_bindgen_test]
+async fn hue_slider_emits_hue_at_pointer() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! { <ColorPicker color=color on_change=move |c| emitted.set(Some(c)) /> }
+    });
+    Executor::tick().await;
+
+    let hue = query::<HtmlElement>(&parent, ".leptos-color-hue-container");
+    let rect = hue.get_bounding_client_rect();
+    let client_x = (rect.left() + rect.width() / 2.0).round();
+    let init = MouseEventInit::new();
+    init.set_bubbles(true);
+    init.set_client_x(client_x as i32);
+    init.set_client_y((rect.top() + rect.height() / 2.0) as i32);
+    let event = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+    hue.dispatch_event(&event).unwrap();
+
+    let expected = (client_x - rect.left()) / rect.width() * 360.0;
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    let actual = emitted.to_hsla()[0] as f64;
+    assert!(
+        (actual - expected).abs() < 1.0,
+        "expected hue {expected}, got {actual}"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hue_flick_comes_to_rest_at_release_position() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! { <ColorPicker color=color on_change=move |c| emitted.set(Some(c)) /> }
+    });
+    Executor::tick().await;
+
+    let hue = query::<HtmlElement>(&parent, ".leptos-color-hue-container");
+    let rect = hue.get_bounding_client_rect();
+    let client_y = (rect.top() + rect.height() / 2.0) as i32;
+    let event = |kind: &str, client_x: f64| {
+        let init = MouseEventInit::new();
+        init.set_bubbles(true);
+        init.set_client_x(client_x as i32);
+        init.set_client_y(client_y);
+        MouseEvent::new_with_mouse_event_init_dict(kind, &init).unwrap()
+    };
+    hue.dispatch_event(&event("mousedown", rect.left().ceil()))
+        .unwrap();
+    Executor::tick().await;
+
+    // Released across the track without a single `mousemove` in between.
+    let release_x = (rect.left() + rect.width() * 0.75).round();
+    document()
+        .dispatch_event(&event("mouseup", release_x))
+        .unwrap();
+
+    let expected = (release_x - rect.left()) / rect.width() * 360.0;
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    let actual = emitted.to_hsla()[0] as f64;
+    assert!(
+        (actual - expected).abs() < 1.0,
+        "expected hue {expected}, got {actual}"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hex_input_updates_preview_variable() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let parent = mount_picker(move || {
+        view! { <ColorPicker color=color on_change=move |c| color.set(c) /> }
+    });
+    Executor::tick().await;
+
+    let input = query::<HtmlInputElement>(&parent, "input[name=hex]");
+    commit_input(&input, "00ff00");
+    Executor::tick().await;
+
+    let container = query::<HtmlElement>(&parent, ".leptos-color-container");
+    let rgba = container.style().get_property_value("--lpc-rgba").unwrap();
+    assert_eq!(rgba, "rgba(0, 255, 0, 1)");
+}
+
+#[wasm_bindgen_test]
+async fn saturation_drag_uses_current_rect_after_resize() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! { <ColorPicker color=color on_change=move |c| emitted.set(Some(c)) /> }
+    });
+    Executor::tick().await;
+
+    let saturation = query::<HtmlElement>(&parent, ".leptos-color-color");
+    let mouse_event = |kind: &str, client_x: f64, client_y: f64| {
+        let init = MouseEventInit::new();
+        init.set_bubbles(true);
+        init.set_client_x(client_x as i32);
+        init.set_client_y(client_y as i32);
+        MouseEvent::new_with_mouse_event_init_dict(kind, &init).unwrap()
+    };
+
+    let rect = saturation.get_bounding_client_rect();
+    saturation
+        .dispatch_event(&mouse_event(
+            "mousedown",
+            rect.left() + 10.0,
+            rect.top() + 10.0,
+        ))
+        .unwrap();
+    Executor::tick().await;
+
+    // Shrink the area mid-drag, then move the pointer.
+    saturation.style().set_property("width", "100px").unwrap();
+    let rect = saturation.get_bounding_client_rect();
+    let client_x = (rect.left() + rect.width() * 0.75).round();
+    document()
+        .dispatch_event(&mouse_event("mousemove", client_x, rect.top() + 10.0))
+        .unwrap();
+
+    let expected = (client_x - rect.left()) / rect.width();
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    let actual = emitted.to_hsva()[1] as f64;
+    assert!(
+        (actual - expected).abs() < 0.02,
+        "expected saturation {expected}, got {actual}"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn saturation_corners_map_to_extremes() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! { <ColorPicker color=color on_change=move |c| emitted.set(Some(c)) /> }
+    });
+    Executor::tick().await;
+
+    let saturation = query::<HtmlElement>(&parent, ".leptos-color-color");
+    let rect = saturation.get_bounding_client_rect();
+    let (left, right) = (rect.left().floor(), rect.right().ceil());
+    let (top, bottom) = (rect.top().floor(), rect.bottom().ceil());
+
+    // (x, y, expected 8-bit color) for the pure red hue.
+    let corners = [
+        (left, top, [255, 255, 255, 255]),
+        (right, top, [255, 0, 0, 255]),
+        (left, bottom, [0, 0, 0, 255]),
+        (right, bottom, [0, 0, 0, 255]),
+    ];
+    for (client_x, client_y, expected) in corners {
+        let init = MouseEventInit::new();
+        init.set_bubbles(true);
+        init.set_client_x(client_x as i32);
+        init.set_client_y(client_y as i32);
+        let event = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+        saturation.dispatch_event(&event).unwrap();
+        let release = MouseEvent::new_with_mouse_event_init_dict("mouseup", &init).unwrap();
+        document().dispatch_event(&release).unwrap();
+        Executor::tick().await;
+
+        let emitted = emitted.get_untracked().expect("on_change was not called");
+        assert_eq!(
+            emitted.to_rgba8(),
+            expected,
+            "corner ({client_x}, {client_y})"
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+async fn value_epsilon_bounds_the_saturation_extremes() {
+    for (epsilon, expected) in [(0.001_f32, 0.001_f32), (0.0, 0.0)] {
+        let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+        let emitted = RwSignal::new(None::<Color>);
+        let parent = mount_picker(move || {
+            view! {
+                <ColorPicker
+                    color=color
+                    value_epsilon=epsilon
+                    on_change=move |c| emitted.set(Some(c))
+                />
+            }
+        });
+        Executor::tick().await;
+
+        // Bottom-left corner: no saturation and no value.
+        let saturation = query::<HtmlElement>(&parent, ".leptos-color-color");
+        let rect = saturation.get_bounding_client_rect();
+        let init = MouseEventInit::new();
+        init.set_bubbles(true);
+        init.set_client_x(rect.left().floor() as i32);
+        init.set_client_y(rect.bottom().ceil() as i32);
+        let event = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+        saturation.dispatch_event(&event).unwrap();
+        let release = MouseEvent::new_with_mouse_event_init_dict("mouseup", &init).unwrap();
+        document().dispatch_event(&release).unwrap();
+        Executor::tick().await;
+
+        let emitted = emitted.get_untracked().expect("on_change was not called");
+        let [_, s, v, _] = emitted.to_hsva();
+        assert!((v - expected).abs() < 1e-6, "epsilon {epsilon}: value {v}");
+        assert_eq!(emitted.to_rgba8(), [0, 0, 0, 255]);
+        if epsilon == 0.0 {
+            assert_eq!(s, 0.0);
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn hex_alpha_is_ignored_while_alpha_is_hidden() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! {
+            <ColorPicker
+                color=color
+                hide_alpha=true
+                on_change=move |c| emitted.set(Some(c))
+            />
+        }
+    });
+    Executor::tick().await;
+
+    let input = query::<HtmlInputElement>(&parent, "input[name=hex]");
+    commit_input(&input, "#11223344");
+
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    assert_eq!(emitted.to_rgba8(), [0x11, 0x22, 0x33, 255]);
+}
+
+#[wasm_bindgen_test]
+async fn strip_alpha_on_output_emits_opaque_colors() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! {
+            <ColorPicker
+                color=color
+                strip_alpha_on_output=true
+                on_change=move |c| {
+                    emitted.set(Some(c));
+                    color.set(c);
+                }
+            />
+        }
+    });
+    Executor::tick().await;
+
+    let alpha = query::<HtmlElement>(&parent, ".leptos-color-alpha-container");
+    let rect = alpha.get_bounding_client_rect();
+    let init = MouseEventInit::new();
+    init.set_bubbles(true);
+    init.set_client_x((rect.left() + rect.width() / 2.0) as i32);
+    init.set_client_y((rect.top() + rect.height() / 2.0) as i32);
+    let event = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+    alpha.dispatch_event(&event).unwrap();
+    Executor::tick().await;
+
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    assert_eq!(emitted.a, 1.0);
+    // The alpha input still previews the picked, mid-range alpha.
+    let input = query::<HtmlInputElement>(&parent, "input[name=alpha]");
+    let shown = input.value().parse::<u8>().unwrap();
+    assert!((100..=155).contains(&shown), "alpha input shows {shown}");
+}
+
+#[wasm_bindgen_test]
+fn initial_markup_positions_the_handles() {
+    let color = RwSignal::new(Color::from_rgba8(0, 255, 255, 255));
+    let parent = mount_picker(move || {
+        view! { <ColorPicker color=color on_change=move |c| color.set(c) /> }
+    });
+
+    // No effect has run yet, so this is what server-rendered HTML shows.
+    let container = query::<HtmlElement>(&parent, ".leptos-color-container");
+    let style = container.get_attribute("style").unwrap();
+    for declaration in [
+        "--lpc-hue-pointer: 50%",
+        "--lpc-saturation-pointer-left: calc(100% - 6px)",
+        "--lpc-saturation-pointer-top: calc(0% - 6px)",
+        "--lpc-rgba: rgba(0, 255, 255, 1)",
+    ] {
+        assert!(
+            style.contains(declaration),
+            "{declaration} missing in {style}"
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+async fn theme_signal_updates_container_variables() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let theme = RwSignal::new(Theme::light());
+    let parent = mount_picker(move || {
+        view! { <ColorPicker theme=theme color=color on_change=move |c| color.set(c) /> }
+    });
+    Executor::tick().await;
+
+    theme.set(Theme::dark());
+    Executor::tick().await;
+
+    let container = query::<HtmlElement>(&parent, ".leptos-color-container");
+    let style = container.style();
+    let expected = Theme::dark().to_style();
+    let expected = expected
+        .split(';')
+        .filter_map(|declaration| declaration.split_once(':'))
+        .find(|(property, _)| property.trim() == "--lpc-background")
+        .map(|(_, value)| value.trim().to_string())
+        .unwrap();
+    let background = style.get_property_value("--lpc-background").unwrap();
+    assert_eq!(background, expected);
+    // The color variables survive the theme change.
+    assert_eq!(
+        style.get_property_value("--lpc-rgba").unwrap(),
+        "rgba(255, 0, 0, 1)"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn swatches_only_layout_emits_selected_swatch() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let palette = vec![
+        Color::from_rgba8(255, 0, 0, 255),
+        Color::from_rgba8(0, 0, 255, 255),
+    ];
+    let parent = mount_picker(move || {
+        view! {
+            <ColorPicker
+                color=color
+                layout=PickerLayout::SwatchesOnly
+                swatches=palette.clone()
+                on_change=move |c| emitted.set(Some(c))
+            />
+        }
+    });
+    Executor::tick().await;
+
+    for selector in [".leptos-color-controls", ".leptos-color-inputs", "input"] {
+        assert!(
+            parent.query_selector(selector).unwrap().is_none(),
+            "{selector} is not rendered"
+        );
+    }
+    let swatches = parent.query_selector_all(".leptos-color-swatch").unwrap();
+    assert_eq!(swatches.length(), 2);
+    swatches
+        .item(1)
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .click();
+    Executor::tick().await;
+
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    assert_eq!(emitted.to_rgba8(), [0, 0, 255, 255]);
+}
+
+#[wasm_bindgen_test]
+async fn non_finite_colors_are_replaced_by_the_current_color() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! {
+            <ColorPicker
+                color=color
+                // A degenerate HSV conversion.
+                transform=|c: Color| Color::from_hsva(f32::NAN, 1.0, 1.0, c.a)
+                on_change=move |c| emitted.set(Some(c))
+            />
+        }
+    });
+    Executor::tick().await;
+
+    let input = query::<HtmlInputElement>(&parent, "input[name=hex]");
+    commit_input(&input, "00ff00");
+    Executor::tick().await;
+
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    assert_eq!(emitted.to_rgba8(), [255, 0, 0, 255]);
+}
+
+#[wasm_bindgen_test]
+async fn hue_input_wraps_out_of_range_values() {
+    for (typed, expected) in [("360", [255, 0, 0, 255]), ("-1", [255, 0, 4, 255])] {
+        let color = RwSignal::new(Color::from_rgba8(0, 0, 255, 255));
+        let emitted = RwSignal::new(None::<Color>);
+        let parent = mount_picker(move || {
+            view! {
+                <ColorPicker
+                    color=color
+                    show_hue_input=true
+                    on_change=move |c| emitted.set(Some(c))
+                />
+            }
+        });
+        Executor::tick().await;
+
+        commit_input(&query(&parent, "input[name=hue]"), typed);
+        Executor::tick().await;
+
+        let emitted = emitted.get_untracked().expect("on_change was not called");
+        let hue = emitted.to_hsla()[0];
+        assert!((0.0..360.0).contains(&hue), "hue {typed} became {hue}");
+        assert_eq!(emitted.to_rgba8(), expected, "hue {typed}");
+    }
+}
+
+#[wasm_bindgen_test]
+async fn alpha_slider_and_input_agree_on_the_emitted_alpha() {
+    let color = RwSignal::new(Color::from_rgba8(255, 0, 0, 255));
+    let emitted = RwSignal::new(Vec::<Color>::new());
+    let parent = mount_picker(move || {
+        view! {
+            <ColorPicker
+                color=color
+                on_change=move |c| emitted.update(|emitted| emitted.push(c))
+            />
+        }
+    });
+    Executor::tick().await;
+
+    // One keyboard step down from 100% is 99%, the input value 252 of 255.
+    let init = KeyboardEventInit::new();
+    init.set_key("ArrowLeft");
+    let step = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    query::<HtmlElement>(&parent, ".leptos-color-alpha-container")
+        .dispatch_event(&step)
+        .unwrap();
+    Executor::tick().await;
+    commit_input(&query(&parent, "input[name=alpha]"), "252");
+    Executor::tick().await;
+
+    let emitted = emitted.get_untracked();
+    assert_eq!(emitted.len(), 2);
+    assert_eq!(emitted[0].a, emitted[1].a);
+    assert_eq!(emitted[0].to_rgba8(), [255, 0, 0, 252]);
+}
+
+#[wasm_bindgen_test]
+async fn grayscale_lightness_slider_steps_with_the_keyboard() {
+    let color = RwSignal::new(Color::from_rgba8(128, 128, 128, 255));
+    let emitted = RwSignal::new(None::<Color>);
+    let parent = mount_picker(move || {
+        view! {
+            <ColorPicker
+                color=color
+                grayscale_only=true
+                on_change=move |c| emitted.set(Some(c))
+            />
+        }
+    });
+    Executor::tick().await;
+
+    let slider = query::<HtmlElement>(&parent, ".leptos-color-lightness");
+    assert_eq!(slider.get_attribute("role").as_deref(), Some("slider"));
+    assert_eq!(
+        slider.get_attribute("aria-label").as_deref(),
+        Some("Lightness")
+    );
+    let init = KeyboardEventInit::new();
+    init.set_key("End");
+    let end = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    slider.dispatch_event(&end).unwrap();
+    Executor::tick().await;
+
+    let emitted = emitted.get_untracked().expect("on_change was not called");
+    assert_eq!(emitted.to_rgba8(), [255, 255, 255, 255]);
+}
@@ -0,0 +1,12 @@
+//! Helpers shared by the browser tests.
+
+use web_sys::{Event, EventInit, HtmlInputElement};
+
+/// Types `value` into `input` and commits it with a `change` event.
+pub fn commit_input(input: &HtmlInputElement, value: &str) {
+    input.set_value(value);
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    let event = Event::new_with_event_init_dict("change", &init).unwrap();
+    input.dispatch_event(&event).unwrap();
+}
@@ -0,0 +1,71 @@
+use leptos_color::{
+    contrast::{accessible_pair, contrast_ratio, readable_text_color, relative_luminance},
+    Color,
+};
+
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+fn gray(value: u8) -> Color {
+    Color::from_rgba8(value, value, value, 255)
+}
+
+#[test]
+fn extremes_get_the_opposite_text_color() {
+    assert_eq!(readable_text_color(&gray(0)).to_rgba8(), WHITE);
+    assert_eq!(readable_text_color(&gray(255)).to_rgba8(), BLACK);
+}
+
+#[test]
+fn switches_to_black_at_equal_contrast() {
+    // Black and white contrast equally at a luminance of sqrt(1.05 * 0.05) - 0.05.
+    assert!(relative_luminance(&gray(117)) < 0.179);
+    assert!(relative_luminance(&gray(118)) > 0.179);
+    assert_eq!(readable_text_color(&gray(117)).to_rgba8(), WHITE);
+    assert_eq!(readable_text_color(&gray(118)).to_rgba8(), BLACK);
+}
+
+#[test]
+fn ignores_alpha() {
+    let translucent = Color::from_rgba8(255, 255, 255, 0);
+    assert_eq!(readable_text_color(&translucent).to_rgba8(), BLACK);
+}
+
+#[test]
+fn accessible_pair_meets_the_ratio() {
+    let backgrounds = [
+        gray(0),
+        gray(255),
+        gray(60),
+        gray(200),
+        Color::from_rgba8(0, 128, 128, 255),
+        Color::from_rgba8(255, 200, 0, 255),
+        Color::from_rgba8(40, 0, 120, 255),
+        Color::from_rgba8(220, 30, 60, 255),
+    ];
+    for background in backgrounds {
+        for min_ratio in [3.0, 4.5, 7.0] {
+            let text = accessible_pair(&background, min_ratio);
+            let extreme = readable_text_color(&background);
+            let best = f64::from(contrast_ratio(&background, &extreme));
+            let ratio = f64::from(contrast_ratio(&background, &text));
+            assert!(
+                ratio >= min_ratio.min(best),
+                "{background:?} at {min_ratio}: got {ratio}"
+            );
+        }
+    }
+}
+
+#[test]
+fn accessible_pair_keeps_the_tint_when_possible() {
+    let teal = Color::from_rgba8(0, 128, 128, 255);
+    let text = accessible_pair(&teal, 3.0);
+    assert_ne!(text.to_rgba8(), readable_text_color(&teal).to_rgba8());
+}
+
+#[test]
+fn unreachable_ratios_fall_back_to_black_or_white() {
+    assert_eq!(accessible_pair(&gray(255), 25.0).to_rgba8(), BLACK);
+    assert_eq!(accessible_pair(&gray(0), 25.0).to_rgba8(), WHITE);
+}
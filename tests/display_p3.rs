@@ -0,0 +1,37 @@
+use leptos_color::{
+    display_p3::{display_p3_to_srgb, srgb_to_display_p3},
+    Color,
+};
+
+fn assert_close(actual: [f32; 4], expected: [f32; 4], tolerance: f32) {
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < tolerance, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn srgb_colors_round_trip_through_display_p3() {
+    for color in [
+        Color::new(1.0, 0.0, 0.0, 1.0),
+        Color::new(0.0, 1.0, 0.0, 1.0),
+        Color::new(0.0, 0.0, 1.0, 1.0),
+        Color::new(1.0, 1.0, 1.0, 1.0),
+        Color::new(0.0, 0.0, 0.0, 1.0),
+        Color::new(0.5, 0.5, 0.5, 1.0),
+        Color::new(1.0, 0.5, 0.0, 0.25),
+        Color::new(0.2, 0.6, 0.8, 0.5),
+    ] {
+        let restored = display_p3_to_srgb(srgb_to_display_p3(&color));
+        assert_close(
+            [restored.r, restored.g, restored.b, restored.a],
+            [color.r, color.g, color.b, color.a],
+            1e-4,
+        );
+    }
+}
+
+#[test]
+fn srgb_red_lies_inside_display_p3() {
+    let p3 = srgb_to_display_p3(&Color::new(1.0, 0.0, 0.0, 1.0));
+    assert_close(p3, [0.9175, 0.2003, 0.1386, 1.0], 1e-3);
+}
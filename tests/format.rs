@@ -0,0 +1,96 @@
+use leptos_color::{
+    format::{
+        format_color, format_decimal, format_number, parse_color, parse_decimal, round_trips,
+        ColorFormat,
+    },
+    Color,
+};
+
+const FORMATS: [ColorFormat; 3] = [ColorFormat::Hex, ColorFormat::Rgba, ColorFormat::DisplayP3];
+
+fn samples() -> Vec<Color> {
+    let mut colors = vec![
+        Color::new(0.0, 0.0, 0.0, 1.0),
+        Color::new(1.0, 1.0, 1.0, 1.0),
+        Color::new(1.0, 0.0, 0.0, 1.0),
+        Color::new(0.0, 1.0, 0.0, 1.0),
+        Color::new(0.0, 0.0, 1.0, 1.0),
+        Color::new(0.5, 0.5, 0.5, 0.5),
+        Color::new(0.2, 0.4, 0.6, 0.0),
+        Color::from_rgba8(18, 52, 86, 120),
+    ];
+    // A coarse grid over the RGB cube with varying alpha.
+    for r in 0..=4 {
+        for g in 0..=4 {
+            for b in 0..=4 {
+                let alpha = ((r + g + b) % 5) as f32 / 4.0;
+                colors.push(Color::new(
+                    r as f32 / 4.0,
+                    g as f32 / 4.0,
+                    b as f32 / 4.0,
+                    alpha,
+                ));
+            }
+        }
+    }
+    colors
+}
+
+#[test]
+fn every_format_round_trips() {
+    for format in FORMATS {
+        for color in samples() {
+            assert!(
+                round_trips(&color, format),
+                "{color:?} did not round-trip through {format:?}: {} parsed as {:?}",
+                format_color(&color, format),
+                parse_color(&format_color(&color, format)),
+            );
+        }
+    }
+}
+
+#[test]
+fn hex_round_trip_is_exact_for_8_bit_colors() {
+    let color = Color::from_rgba8(18, 52, 86, 120);
+    let parsed = parse_color(&format_color(&color, ColorFormat::Hex)).unwrap();
+    assert_eq!(parsed.to_rgba8(), color.to_rgba8());
+}
+
+#[test]
+fn invalid_strings_do_not_parse() {
+    assert_eq!(parse_color("not a color"), None);
+    assert_eq!(parse_color("color(display-p3 1 0)"), None);
+}
+
+#[test]
+fn decimals_parse_with_either_separator() {
+    assert_eq!(parse_decimal("12.5", '.'), Some(12.5));
+    assert_eq!(parse_decimal("12,5", ','), Some(12.5));
+    assert_eq!(parse_decimal(" 12.5 ", ','), Some(12.5));
+    assert_eq!(parse_decimal("12,5", '.'), None);
+    assert_eq!(parse_decimal("twelve", ','), None);
+}
+
+#[test]
+fn decimals_format_with_separator() {
+    assert_eq!(format_decimal("127.50", '.'), "127.50");
+    assert_eq!(format_decimal("127.50", ','), "127,50");
+    assert_eq!(
+        parse_decimal(&format_decimal("127.50", ','), ','),
+        Some(127.5)
+    );
+}
+
+#[test]
+fn numbers_format_without_grouping_under_a_comma_locale() {
+    assert_eq!(format_number(1234.5, 2, ','), "1234,50");
+    assert_eq!(format_number(123456.0, 0, ','), "123456");
+    assert_eq!(format_number(359.96, 1, ','), "360,0");
+    assert_eq!(format_number(-0.04, 1, ','), "0,0");
+    assert_eq!(format_number(-12.5, 1, ','), "-12,5");
+    assert_eq!(
+        parse_decimal(&format_number(1234.567, 3, ','), ','),
+        Some(1234.567)
+    );
+}
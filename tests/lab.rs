@@ -0,0 +1,45 @@
+#![cfg(feature = "lab")]
+
+use leptos_color::{
+    lab::{lab_to_srgb, lab_to_srgb_checked, srgb_to_lab},
+    Color,
+};
+
+fn assert_lab(color: Color, expected: [f32; 3]) {
+    let lab = srgb_to_lab(&color);
+    for (actual, expected) in lab.iter().zip(expected) {
+        assert!((actual - expected).abs() < 0.05, "{lab:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn srgb_colors_match_reference_lab_values() {
+    assert_lab(Color::from_rgba8(255, 255, 255, 255), [100.0, 0.0, 0.0]);
+    assert_lab(Color::from_rgba8(0, 0, 0, 255), [0.0, 0.0, 0.0]);
+    assert_lab(Color::from_rgba8(128, 128, 128, 255), [53.59, 0.0, 0.0]);
+    assert_lab(Color::from_rgba8(255, 0, 0, 255), [53.24, 80.09, 67.20]);
+    assert_lab(Color::from_rgba8(0, 255, 0, 255), [87.73, -86.18, 83.18]);
+    assert_lab(Color::from_rgba8(0, 0, 255, 255), [32.30, 79.19, -107.86]);
+}
+
+#[test]
+fn reference_lab_values_convert_back_to_srgb() {
+    assert_eq!(
+        lab_to_srgb([53.24, 80.09, 67.20], 1.0).to_rgba8(),
+        [255, 0, 0, 255]
+    );
+    for rgba in [[0, 128, 128, 255], [255, 136, 0, 128], [18, 52, 86, 255]] {
+        let color = Color::from_rgba8(rgba[0], rgba[1], rgba[2], rgba[3]);
+        assert_eq!(lab_to_srgb(srgb_to_lab(&color), color.a).to_rgba8(), rgba);
+    }
+}
+
+#[test]
+fn out_of_gamut_values_are_clamped_and_reported() {
+    let (color, clamped) = lab_to_srgb_checked([50.0, 120.0, 0.0], 1.0);
+    assert!(clamped);
+    assert!([color.r, color.g, color.b]
+        .iter()
+        .all(|c| (0.0..=1.0).contains(c)));
+    assert!(!lab_to_srgb_checked([53.24, 0.0, 0.0], 1.0).1);
+}
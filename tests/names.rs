@@ -0,0 +1,30 @@
+use leptos_color::{
+    names::{name_of, named_color, NAMED_COLORS},
+    Color,
+};
+
+#[test]
+fn table_is_sorted_and_complete() {
+    assert_eq!(NAMED_COLORS.len(), 148);
+    assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn every_name_matches_the_css_parser() {
+    for (name, [r, g, b]) in NAMED_COLORS {
+        let parsed = name.parse::<Color>().unwrap();
+        assert_eq!(parsed.to_rgba8(), [r, g, b, 255], "{name}");
+        assert_eq!(named_color(name), Some(parsed));
+    }
+}
+
+#[test]
+fn names_are_found_for_exact_opaque_matches_only() {
+    assert_eq!(name_of(&Color::from_rgba8(0, 255, 255, 255)), Some("aqua"));
+    assert_eq!(
+        name_of(&Color::from_rgba8(102, 51, 153, 255)),
+        Some("rebeccapurple")
+    );
+    assert_eq!(name_of(&Color::from_rgba8(102, 51, 154, 255)), None);
+    assert_eq!(name_of(&Color::from_rgba8(255, 0, 0, 128)), None);
+}
@@ -0,0 +1,111 @@
+use leptos_color::{
+    palette::{parse_palette, PaletteParseError},
+    Color,
+};
+
+#[test]
+fn parses_json_in_file_order() {
+    let palette = parse_palette(
+        r##"{
+            "primary": "#3b82f6",
+            "danger": "rgb(239, 68, 68)",
+            "muted": "gray"
+        }"##,
+    )
+    .unwrap();
+    assert_eq!(
+        palette,
+        vec![
+            ("primary".to_string(), Color::from_rgba8(59, 130, 246, 255)),
+            ("danger".to_string(), Color::from_rgba8(239, 68, 68, 255)),
+            ("muted".to_string(), Color::from_rgba8(128, 128, 128, 255)),
+        ]
+    );
+}
+
+#[test]
+fn json_escapes_are_decoded() {
+    let palette =
+        parse_palette(r#"{ "tab\tquote\"\u00e9\ud83c\udfa8": "red", "a\/b": "blue" }"#).unwrap();
+    assert_eq!(palette[0].0, "tab\tquote\"é🎨");
+    assert_eq!(palette[1].0, "a/b");
+}
+
+#[test]
+fn invalid_json_escapes_are_rejected() {
+    for source in [
+        r#"{ "a\q": "red" }"#,
+        r#"{ "a\u12": "red" }"#,
+        r#"{ "\ud83c": "red" }"#,
+    ] {
+        assert!(
+            matches!(parse_palette(source), Err(PaletteParseError::Syntax(_))),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn parses_toml_skipping_comments_and_tables() {
+    let palette = parse_palette(
+        r##"
+        # Brand colors
+        [colors]
+        primary = "#3b82f6"
+        danger = "red"
+        "##,
+    )
+    .unwrap();
+    assert_eq!(
+        palette,
+        vec![
+            ("primary".to_string(), Color::from_rgba8(59, 130, 246, 255)),
+            ("danger".to_string(), Color::from_rgba8(255, 0, 0, 255)),
+        ]
+    );
+}
+
+#[test]
+fn toml_values_may_carry_trailing_comments() {
+    let palette = parse_palette(
+        r##"
+        primary = "#3b82f6" # brand blue
+        danger = "red"#alerts
+        "##,
+    )
+    .unwrap();
+    assert_eq!(
+        palette,
+        vec![
+            ("primary".to_string(), Color::from_rgba8(59, 130, 246, 255)),
+            ("danger".to_string(), Color::from_rgba8(255, 0, 0, 255)),
+        ]
+    );
+    assert!(matches!(
+        parse_palette(r#"primary = "red" blue"#),
+        Err(PaletteParseError::Syntax(_))
+    ));
+}
+
+#[test]
+fn syntax_errors_are_reported() {
+    assert!(matches!(
+        parse_palette(r#"{ "primary" "red" }"#),
+        Err(PaletteParseError::Syntax(_))
+    ));
+    assert!(matches!(
+        parse_palette("primary: red"),
+        Err(PaletteParseError::Syntax(_))
+    ));
+}
+
+#[test]
+fn invalid_colors_name_the_entry() {
+    assert_eq!(
+        parse_palette(r#"{ "primary": "not-a-color" }"#),
+        Err(PaletteParseError::InvalidColor {
+            name: "primary".to_string(),
+            value: "not-a-color".to_string(),
+        })
+    );
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "palettes")]
+
+use leptos_color::{
+    palettes::{colors, material, named_colors, tailwind, MATERIAL, TAILWIND},
+    Color,
+};
+
+#[test]
+fn scales_have_every_shade() {
+    assert_eq!(TAILWIND.len(), 22);
+    assert!(TAILWIND.iter().all(|(_, scale)| scale.len() == 11));
+    assert_eq!(MATERIAL.len(), 19);
+    for (name, scale) in MATERIAL {
+        let expected = if matches!(*name, "brown" | "grey" | "blue-grey") {
+            10
+        } else {
+            14
+        };
+        assert_eq!(scale.len(), expected, "{name}");
+    }
+}
+
+#[test]
+fn entries_are_named_after_their_scale() {
+    for (name, scale) in TAILWIND.iter().chain(MATERIAL) {
+        for (entry, _) in *scale {
+            assert!(entry.starts_with(&format!("{name}-")), "{entry}");
+        }
+    }
+}
+
+#[test]
+fn scales_are_found_by_name() {
+    assert_eq!(
+        tailwind("Emerald").map(|scale| scale[0].0),
+        Some("emerald-50")
+    );
+    assert_eq!(
+        material("deep-purple").map(|scale| scale[5]),
+        Some(("deep-purple-500", "#673ab7"))
+    );
+    assert_eq!(tailwind("deep-purple"), None);
+}
+
+#[test]
+fn scales_parse_into_colors() {
+    let red = tailwind("red").unwrap();
+    let named = named_colors(red);
+    assert_eq!(
+        named[5],
+        ("red-500".to_string(), Color::from_rgba8(239, 68, 68, 255))
+    );
+    assert_eq!(
+        colors(red),
+        named
+            .into_iter()
+            .map(|(_, color)| color)
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,41 @@
+use leptos_color::theme::{Theme, ThemeData, ThemeParseError};
+
+#[test]
+fn themes_round_trip_through_theme_data() {
+    for theme in [Theme::light(), Theme::dark()] {
+        let data = ThemeData::from(&theme);
+        let restored = Theme::try_from(data.clone()).unwrap();
+        assert_eq!(restored.to_style(), theme.to_style());
+        assert_eq!(ThemeData::from(&restored), data);
+    }
+}
+
+#[test]
+fn translucent_colors_keep_their_alpha() {
+    let data = ThemeData::from(&Theme::dark());
+    assert_eq!(data.background, "#282828f2");
+    let restored = Theme::try_from(data).unwrap();
+    assert_eq!(restored.to_style(), Theme::dark().to_style());
+}
+
+#[test]
+fn invalid_colors_name_the_field() {
+    let data = ThemeData {
+        border_color: "not a color".to_string(),
+        ..ThemeData::from(&Theme::light())
+    };
+    assert_eq!(
+        Theme::try_from(data).err(),
+        Some(ThemeParseError::InvalidColor {
+            property: "border_color".to_string(),
+            value: "not a color".to_string(),
+        })
+    );
+}
+
+#[test]
+fn themes_round_trip_through_their_style() {
+    for theme in [Theme::light(), Theme::dark()] {
+        assert_eq!(Theme::from_style(&theme.to_style()).ok(), Some(theme));
+    }
+}